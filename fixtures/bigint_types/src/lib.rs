@@ -0,0 +1,114 @@
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+#[uniffi::export]
+fn max_u64() -> u64 {
+    u64::MAX
+}
+
+#[uniffi::export]
+fn echo_u64(value: u64) -> u64 {
+    value
+}
+
+#[uniffi::export]
+fn add_u64(a: u64, b: u64) -> u64 {
+    a.wrapping_add(b)
+}
+
+#[uniffi::export]
+fn maybe_u64(value: Option<u64>) -> Option<u64> {
+    value
+}
+
+#[uniffi::export]
+fn sum_u64s(values: Vec<u64>) -> u64 {
+    values.into_iter().fold(0, u64::wrapping_add)
+}
+
+#[uniffi::export]
+fn total_size(sizes: HashMap<String, u64>) -> u64 {
+    sizes.into_values().fold(0, u64::wrapping_add)
+}
+
+#[uniffi::export]
+fn split_sizes(sizes: HashMap<String, u64>) -> HashMap<u64, Vec<String>> {
+    let mut split = HashMap::<u64, Vec<String>>::new();
+    for (name, size) in sizes {
+        split.entry(size).or_default().push(name);
+    }
+    split
+}
+
+#[uniffi::export]
+fn maybe_sizes(sizes: Option<HashMap<String, u64>>) -> Option<HashMap<String, u64>> {
+    sizes
+}
+
+#[uniffi::export]
+async fn sizes_later(sizes: HashMap<String, u64>) -> HashMap<String, u64> {
+    sizes
+}
+
+#[uniffi::export(callback_interface)]
+pub trait Accumulator: Send + Sync {
+    fn add(&self, total: u64, value: u64) -> u64;
+    fn largest(&self, values: Vec<u64>) -> Option<u64>;
+}
+
+#[uniffi::export]
+fn accumulate(accumulator: Box<dyn Accumulator>, values: Vec<u64>) -> u64 {
+    values
+        .iter()
+        .fold(0, |total, value| accumulator.add(total, *value))
+}
+
+#[uniffi::export]
+fn largest_of(accumulator: Box<dyn Accumulator>, values: Vec<u64>) -> Option<u64> {
+    accumulator.largest(values)
+}
+
+// `u64` fields are read and written through the same converter
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct FileChunk {
+    offset: u64,
+    hash: u64,
+    length: u32,
+    blocks: HashMap<String, u64>,
+}
+
+#[uniffi::export]
+fn next_chunk(chunk: FileChunk) -> FileChunk {
+    FileChunk {
+        offset: chunk.offset.wrapping_add(chunk.length as u64),
+        hash: !chunk.hash,
+        length: chunk.length,
+        blocks: chunk.blocks,
+    }
+}
+
+#[derive(uniffi::Object)]
+pub struct Hasher {
+    state: Mutex<u64>,
+}
+
+#[uniffi::export]
+impl Hasher {
+    #[uniffi::constructor]
+    fn new(seed: u64) -> Self {
+        Self {
+            state: Mutex::new(seed),
+        }
+    }
+
+    fn write(&self, value: u64) {
+        let mut state = self.state.lock().unwrap();
+        *state = state.rotate_left(5) ^ value;
+    }
+
+    fn finish(&self) -> u64 {
+        *self.state.lock().unwrap()
+    }
+}
+
+uniffi::include_scaffolding!("api");
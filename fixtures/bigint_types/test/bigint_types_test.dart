@@ -0,0 +1,74 @@
+import 'package:test/test.dart';
+import '../bigint_types.dart';
+
+final u64Max = (BigInt.one << 64) - BigInt.one;
+
+class SummingAccumulator extends Accumulator {
+  final seen = <BigInt>[];
+
+  @override
+  BigInt add(BigInt total, BigInt value) {
+    seen.add(value);
+    return total + value;
+  }
+
+  @override
+  BigInt? largest(List<BigInt> values) =>
+      values.isEmpty ? null : values.reduce((a, b) => a > b ? a : b);
+}
+
+void main() {
+  test('values above 2^63 keep their sign', () {
+    expect(maxU64(), u64Max);
+    expect(echoU64(u64Max), u64Max);
+    expect(echoU64(BigInt.one << 63), BigInt.one << 63);
+    expect(addU64(u64Max, BigInt.two), BigInt.one);
+  });
+
+  test('out of range values are rejected', () {
+    expect(() => echoU64(BigInt.from(-1)), throwsArgumentError);
+    expect(() => echoU64(BigInt.one << 64), throwsArgumentError);
+  });
+
+  test('compound types', () {
+    expect(maybeU64(null), isNull);
+    expect(maybeU64(u64Max), u64Max);
+    expect(sumU64s([u64Max, BigInt.from(3)]), BigInt.two);
+    expect(totalSize({'a': BigInt.one << 63, 'b': BigInt.one << 62}), (BigInt.one << 63) + (BigInt.one << 62));
+  });
+
+  test('maps', () async {
+    final Map<BigInt, List<String>> split = splitSizes({'a': u64Max, 'b': u64Max, 'c': BigInt.one});
+    expect(split.keys.toSet(), {u64Max, BigInt.one});
+    expect(split[u64Max]!..sort(), ['a', 'b']);
+
+    expect(maybeSizes(null), isNull);
+    expect(maybeSizes({'a': u64Max}), {'a': u64Max});
+    final Map<String, BigInt> later = await sizesLater({'a': BigInt.one << 63});
+    expect(later, {'a': BigInt.one << 63});
+  });
+
+  test('callback interfaces', () {
+    final accumulator = SummingAccumulator();
+    expect(accumulate(accumulator, [BigInt.one << 63, BigInt.one << 62, BigInt.one]),
+        (BigInt.one << 63) + (BigInt.one << 62) + BigInt.one);
+    expect(accumulator.seen, [BigInt.one << 63, BigInt.one << 62, BigInt.one]);
+
+    expect(largestOf(accumulator, [BigInt.two, u64Max, BigInt.one << 63]), u64Max);
+    expect(largestOf(accumulator, []), isNull);
+  });
+
+  test('records', () {
+    final chunk = nextChunk(FileChunk(u64Max - BigInt.from(9), BigInt.zero, 4, {'first': u64Max}));
+    expect(chunk.offset, u64Max - BigInt.from(5));
+    expect(chunk.hash, u64Max);
+    expect(chunk.length, 4);
+    expect(chunk.blocks, {'first': u64Max});
+  });
+
+  test('objects', () {
+    final hasher = Hasher(u64Max);
+    hasher.write(BigInt.one);
+    expect(hasher.finish(), u64Max - BigInt.one);
+  });
+}
@@ -0,0 +1,6 @@
+use anyhow::Result;
+
+#[test]
+fn bigint_types() -> Result<()> {
+    uniffi_dart::testing::run_test("bigint_types", "src/api.udl", Some("uniffi.toml"))
+}
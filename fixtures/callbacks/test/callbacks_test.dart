@@ -9,11 +9,11 @@ class DartGetters extends ForeignGetters {
   String getString(String v, bool arg2) {
     if (v == 'BadArgument') {
       // Throw a UniFFI-generated exception type corresponding to BadArgument
-      throw SimpleException.badArgument;
+      throw BadArgumentSimpleException('bad argument');
     }
     if (v == 'UnexpectedException') {
       // Throw a UniFFI-generated exception type corresponding to UnexpectedError
-      throw SimpleException.unexpectedError;
+      throw UnexpectedExceptionSimpleException('unexpected error');
     }
     return arg2 ? v : '1234567890123';
   }
@@ -35,10 +35,10 @@ class DartGetters extends ForeignGetters {
   @override
   void getNothing(String v) {
     if (v == 'BadArgument') {
-      throw SimpleException.badArgument;
+      throw BadArgumentSimpleException('bad argument');
     }
     if (v == 'UnexpectedError') {
-      throw SimpleException.unexpectedError;
+      throw UnexpectedExceptionSimpleException('unexpected error');
     }
   }
 }
@@ -0,0 +1,11 @@
+class Handle {
+  final int value;
+
+  const Handle(this.value);
+
+  @override
+  bool operator ==(Object other) => other is Handle && other.value == value;
+
+  @override
+  int get hashCode => value.hashCode;
+}
@@ -1,17 +1,39 @@
 import 'package:test/test.dart';
 import '../custom_types.dart';
+import '../handle.dart';
 
 void main() {
-  final api = Api.load();
-  
-  final demo = api.getCustomTypesDemo(null);
+  test('configured custom types are lifted into Dart types', () {
+    final demo = getCustomTypesDemo(null);
+    expect(demo.url, Uri.parse('http://example.com/'));
+    expect(demo.handle, Handle(123));
+    expect(demo.timeIntervalMs, Duration(seconds: 456));
+    expect(demo.timeIntervalSecDbl, 456.0);
+    expect(demo.timeIntervalSecFlt, 777.0);
+  });
 
-  assert(demo.url.toString() == 'http://example.com/');
-  
-  assert(demo.handle == 123);
+  test('configured custom types round-trip through records', () {
+    final demo = CustomTypesDemo(
+      Uri.parse('http://new.example.com/'),
+      Handle(456),
+      Duration(milliseconds: 1500),
+      1.5,
+      2.5,
+    );
+    final result = getCustomTypesDemo(demo);
+    expect(result.url, Uri.parse('http://new.example.com/'));
+    expect(result.handle, Handle(456));
+    expect(result.timeIntervalMs, Duration(milliseconds: 1500));
+    expect(result.timeIntervalSecDbl, 1.5);
+    expect(result.timeIntervalSecFlt, 2.5);
+  });
 
-  demo.url = Uri.parse('http://new.example.com/');
-  demo.handle = 456;
-  
-  assert(demo == api.getCustomTypesDemo(demo));
+  test('configured custom types as arguments and return values', () {
+    expect(getUrlHost(Uri.parse('https://dart.dev/guides')), 'dart.dev');
+    expect(joinUrl(Uri.parse('https://dart.dev/guides/'), 'language'),
+        Uri.parse('https://dart.dev/guides/language'));
+    expect(nextHandle(Handle(41)), Handle(42));
+    expect(getHandles(3), [Handle(0), Handle(1), Handle(2)]);
+    expect(doubleInterval(Duration(seconds: 2)), Duration(seconds: 4));
+  });
 }
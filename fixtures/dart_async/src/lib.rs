@@ -1,5 +1,3 @@
-use uniffi;
-
 use std::{
     future::Future,
     pin::Pin,
@@ -194,4 +192,43 @@ pub async fn broken_sleep(ms: u16, fail_after: u16) {
     .await;
 }
 
+// An object whose constructors have to await.
+#[derive(uniffi::Object)]
+pub struct Megaphone {
+    prefix: String,
+}
+
+#[uniffi::export]
+impl Megaphone {
+    #[uniffi::constructor]
+    pub async fn new() -> Arc<Self> {
+        TimerFuture::new(Duration::from_millis(50)).await;
+        Arc::new(Self {
+            prefix: "Hello".to_string(),
+        })
+    }
+
+    #[uniffi::constructor]
+    pub async fn with_prefix(prefix: String, do_fail: bool) -> Result<Arc<Self>, MyError> {
+        TimerFuture::new(Duration::from_millis(50)).await;
+        if do_fail {
+            Err(MyError::Foo)
+        } else {
+            Ok(Arc::new(Self { prefix }))
+        }
+    }
+
+    #[uniffi::constructor]
+    pub fn shout(prefix: String) -> Arc<Self> {
+        Arc::new(Self {
+            prefix: prefix.to_uppercase(),
+        })
+    }
+
+    pub async fn say_after(&self, ms: u16, who: String) -> String {
+        TimerFuture::new(Duration::from_millis(ms.into())).await;
+        format!("{}, {who}!", self.prefix)
+    }
+}
+
 uniffi::include_scaffolding!("api");
@@ -77,22 +77,12 @@ void main() {
 
   test('fallible_function_and_method', () async {
     final time1 = await measureTime(() async {
-      try {
-        fallibleMe(false);
-        expect(true, true);
-      } catch (exception) {
-        expect(false, true); // should never be reached
-      }
+      expect(await fallibleMe(false), 42);
     });
     expect(time1.inMilliseconds <= 100, true);
 
     final time2 = await measureTime(() async {
-      try {
-        fallibleMe(true);
-        expect(false, true); // should never be reached
-      } catch (exception) {
-        expect(true, true);
-      }
+      await expectLater(fallibleMe(true), throwsA(isA<FooMyException>()));
     });
     expect(time2.inMilliseconds <= 100, true);
   });
@@ -118,4 +108,16 @@ void main() {
     });
     expect(time.inMilliseconds >= 400 && time.inMilliseconds <= 600, true);
   });
+
+  test('async constructors', () async {
+    final megaphone = await Megaphone.create();
+    expect(await megaphone.sayAfter(10, 'Alice'), 'Hello, Alice!');
+
+    final custom = await Megaphone.withPrefix('Hi', false);
+    expect(await custom.sayAfter(10, 'Bob'), 'Hi, Bob!');
+
+    await expectLater(Megaphone.withPrefix('Hi', true), throwsA(isA<FooMyException>()));
+
+    expect(await Megaphone.shout('hey').sayAfter(10, 'Carol'), 'HEY, Carol!');
+  });
 }
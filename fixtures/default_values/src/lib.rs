@@ -0,0 +1,144 @@
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+pub enum Theme {
+    Light,
+    Dark,
+}
+
+pub enum Shape {
+    Point,
+    Circle { radius: f64 },
+}
+
+pub struct Settings {
+    name: String,
+    theme: Theme,
+    cursor: Shape,
+    retries: u32,
+    verbose: bool,
+    nickname: Option<String>,
+    tags: Vec<String>,
+    limits: HashMap<String, u32>,
+    motto: String,
+}
+
+fn greet(name: String, greeting: String) -> String {
+    format!("{greeting}, {name}!")
+}
+
+fn offset(value: i64, by: i64, scale: f64) -> i64 {
+    ((value + by) as f64 * scale) as i64
+}
+
+fn describe(settings: Settings) -> String {
+    let theme = match settings.theme {
+        Theme::Light => "light",
+        Theme::Dark => "dark",
+    };
+    let cursor = match settings.cursor {
+        Shape::Point => "point".to_string(),
+        Shape::Circle { radius } => format!("circle({radius})"),
+    };
+    format!(
+        "{} ({}) theme={theme} cursor={cursor} retries={} verbose={} tags={} limits={} motto={}",
+        settings.name,
+        settings.nickname.as_deref().unwrap_or("-"),
+        settings.retries,
+        settings.verbose,
+        settings.tags.len(),
+        settings.limits.len(),
+        settings.motto,
+    )
+}
+
+pub struct Counter {
+    value: Mutex<u32>,
+}
+
+impl Counter {
+    fn new(start: u32) -> Self {
+        Self {
+            value: Mutex::new(start),
+        }
+    }
+
+    fn add(&self, step: u32) -> u32 {
+        let mut value = self.value.lock().unwrap();
+        *value += step;
+        *value
+    }
+}
+
+// Defaults declared through the proc-macros rather than UDL
+#[derive(uniffi::Record)]
+pub struct RetryPolicy {
+    pub attempts: u32,
+    #[uniffi(default = Some(250))]
+    pub delay_ms: Option<u32>,
+    #[uniffi(default = [])]
+    pub retry_on: Vec<String>,
+    #[uniffi(default = true)]
+    pub jitter: bool,
+}
+
+#[uniffi::export(default(policy = None, prefix = "> "))]
+fn describe_policy(policy: Option<RetryPolicy>, prefix: String) -> String {
+    match policy {
+        None => format!("{prefix}no retries"),
+        Some(p) => format!(
+            "{prefix}{} attempts, {}ms, {} errors, jitter={}",
+            p.attempts,
+            p.delay_ms.unwrap_or(0),
+            p.retry_on.len(),
+            p.jitter
+        ),
+    }
+}
+
+// `u64` is exposed as `BigInt` (see uniffi.toml), whose defaults have no const form
+#[derive(uniffi::Record)]
+pub struct Quota {
+    pub name: String,
+    #[uniffi(default = 18446744073709551615)]
+    pub bytes: u64,
+    #[uniffi(default = Some(1000))]
+    pub files: Option<u64>,
+}
+
+#[uniffi::export]
+fn describe_quota(quota: Quota) -> String {
+    format!(
+        "{}: {} bytes, {:?} files",
+        quota.name, quota.bytes, quota.files
+    )
+}
+
+#[uniffi::export(default(by = 10))]
+fn grow(value: u64, by: u64) -> u64 {
+    value.wrapping_add(by)
+}
+
+#[derive(uniffi::Object)]
+pub struct Meter {
+    value: Mutex<u64>,
+}
+
+#[uniffi::export]
+impl Meter {
+    #[uniffi::constructor(default(start = 9223372036854775808))]
+    fn new(start: u64) -> Self {
+        Self {
+            value: Mutex::new(start),
+        }
+    }
+
+    #[uniffi::method(default(step = 1))]
+    fn tick(&self, step: u64) -> u64 {
+        let mut value = self.value.lock().unwrap();
+        *value += step;
+        *value
+    }
+}
+
+uniffi::include_scaffolding!("api");
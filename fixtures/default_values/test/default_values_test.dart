@@ -0,0 +1,88 @@
+import 'package:test/test.dart';
+import '../default_values.dart';
+
+void main() {
+  test('function arguments', () {
+    expect(greet('Dart'), 'Hello, Dart!');
+    expect(greet('Dart', greeting: 'Hi'), 'Hi, Dart!');
+    expect(offset(10), 7);
+    expect(offset(10, by: 2, scale: 2.0), 24);
+  });
+
+  test('record fields', () {
+    final settings = Settings('app');
+    expect(settings.theme, Theme.dark);
+    expect(settings.cursor, isA<PointShape>());
+    expect(settings.retries, 3);
+    expect(settings.verbose, false);
+    expect(settings.nickname, isNull);
+    expect(settings.tags, isEmpty);
+    expect(settings.limits, isEmpty);
+    expect(settings.motto, r"It's $5");
+    expect(describe(settings),
+        r"app (-) theme=dark cursor=point retries=3 verbose=false tags=0 limits=0 motto=It's $5");
+
+    final custom = Settings('app',
+        theme: Theme.light, cursor: CircleShape(2.0), retries: 1, nickname: 'a', tags: ['x']);
+    expect(describe(custom),
+        r"app (a) theme=light cursor=circle(2) retries=1 verbose=false tags=1 limits=0 motto=It's $5");
+  });
+
+  test('proc-macro defaults', () {
+    expect(describePolicy(), '> no retries');
+    expect(describePolicy(policy: RetryPolicy(2)), '> 2 attempts, 250ms, 0 errors, jitter=true');
+    expect(describePolicy(policy: RetryPolicy(1, delayMs: null, jitter: false), prefix: ''),
+        '1 attempts, 0ms, 0 errors, jitter=false');
+  });
+
+  test('records copyWith', () {
+    final base = Settings('app', nickname: 'a', tags: ['x']);
+    final renamed = base.copyWith(name: 'other', retries: 7);
+    expect(renamed.name, 'other');
+    expect(renamed.retries, 7);
+    expect(renamed.nickname, 'a');
+    expect(renamed.tags, ['x']);
+    expect(base.name, 'app');
+
+    expect(base.copyWith(), base);
+    expect(base.copyWith(nickname: () => 'b').nickname, 'b');
+    expect(base.copyWith(nickname: () => null).nickname, isNull);
+
+    final policy = RetryPolicy(3).copyWith(delayMs: () => null, jitter: false);
+    expect(policy, RetryPolicy(3, delayMs: null, jitter: false));
+  });
+
+  test('records are const-constructible', () {
+    const policy = RetryPolicy(2, retryOn: ['timeout']);
+    expect(identical(policy, const RetryPolicy(2, retryOn: ['timeout'])), isTrue);
+    expect(describePolicy(policy: policy), '> 2 attempts, 250ms, 1 errors, jitter=true');
+  });
+
+  test('constructors and methods', () {
+    final counter = Counter();
+    expect(counter.add(), 1);
+    expect(counter.add(step: 5), 6);
+    expect(Counter(start: 10).add(), 11);
+  });
+
+  test('BigInt defaults', () {
+    expect(grow(BigInt.one), BigInt.from(11));
+    expect(grow(BigInt.one, by: BigInt.two), BigInt.from(3));
+
+    final quota = Quota('disk');
+    expect(quota.bytes, BigInt.parse('18446744073709551615'));
+    expect(quota.files, BigInt.from(1000));
+    expect(describeQuota(quota), 'disk: 18446744073709551615 bytes, Some(1000) files');
+    expect(Quota('disk', bytes: BigInt.zero).bytes, BigInt.zero);
+
+    // Lifted and copied records keep a missing optional value rather than taking the default
+    final noFiles = quota.copyWith(files: () => null);
+    expect(noFiles.files, isNull);
+    expect(FfiConverterQuota.lift(FfiConverterQuota.lower(noFiles)), noFiles);
+
+    final meter = Meter();
+    expect(meter.tick(), BigInt.parse('9223372036854775809'));
+    expect(meter.tick(step: BigInt.two), BigInt.parse('9223372036854775811'));
+    expect(Meter(start: BigInt.zero).tick(), BigInt.one);
+  });
+}
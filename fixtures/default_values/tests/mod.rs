@@ -0,0 +1,6 @@
+use anyhow::Result;
+
+#[test]
+fn default_values() -> Result<()> {
+    uniffi_dart::testing::run_test("default_values", "src/api.udl", Some("uniffi.toml"))
+}
@@ -1,5 +1,3 @@
-use uniffi;
-
 use once_cell::sync::Lazy;
 use std::collections::HashMap;
 use std::sync::{Arc, RwLock};
@@ -9,12 +7,23 @@ static LIVE_COUNT: Lazy<RwLock<i32>> = Lazy::new(|| RwLock::new(0));
 #[derive(Debug, Clone, uniffi::Object)]
 pub struct Resource {}
 
+impl Default for Resource {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[uniffi::export]
 impl Resource {
     #[uniffi::constructor]
     pub fn new() -> Self {
         *LIVE_COUNT.write().unwrap() += 1;
         Resource {}
     }
+
+    pub fn ping(&self) -> bool {
+        true
+    }
 }
 
 impl Drop for Resource {
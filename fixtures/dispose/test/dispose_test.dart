@@ -2,40 +2,103 @@ import 'package:test/test.dart';
 import '../dispose.dart';
 
 void main() {
-  final api = Api.load();
+  test('dispose frees the Rust object', () {
+    final before = getLiveCount();
+    final resource = getResource();
+    expect(getLiveCount(), before + 1);
+    expect(resource.isDisposed, isFalse);
+    resource.dispose();
+    expect(resource.isDisposed, isTrue);
+    expect(getLiveCount(), before);
+  });
 
-  test('ObjectDecrementsLiveCount', () {
-    final resource = api.getResource();
-    expect(api.getLiveCount(), 1);
+  test('dispose is idempotent', () {
+    final before = getLiveCount();
+    final resource = Resource();
     resource.dispose();
-    expect(api.getLiveCount(), 0);
+    resource.dispose();
+    expect(getLiveCount(), before);
   });
 
-  test('MapDecrementsLiveCount', () {
-    final journal = api.getResourceJournalMap();
-    expect(api.getLiveCount(), 2);
-    journal.dispose();
-    expect(api.getLiveCount(), 0);
+  test('disposed objects cannot be used', () {
+    final resource = Resource();
+    final journal = ResourceJournalList([resource]);
+    resource.dispose();
+    expect(
+      () => FfiConverterResourceJournalList.lower(journal),
+      throwsA(isA<StateError>().having((e) => e.message, 'message', contains('Resource'))),
+    );
+  });
+
+  test('methods of disposed objects throw', () {
+    final resource = Resource();
+    expect(resource.ping(), isTrue);
+    resource.dispose();
+    expect(
+      () => resource.ping(),
+      throwsA(isA<StateError>().having((e) => e.message, 'message', contains('Resource'))),
+    );
   });
 
-  test('ListDecrementsLiveCount', () {
-    final journal = api.getResourceJournalList();
-    expect(api.getLiveCount(), 2);
-    journal.dispose();
-    expect(api.getLiveCount(), 0);
+  test('objects in a list are disposed one by one', () {
+    final before = getLiveCount();
+    final journal = getResourceJournalList();
+    expect(getLiveCount(), before + 2);
+    for (final resource in journal.resources) {
+      resource.dispose();
+    }
+    expect(getLiveCount(), before);
   });
 
-  test('MapListDecrementsLiveCount', () {
-    final journal = api.getResourceJournalMapList();
-    expect(api.getLiveCount(), 2);
-    journal.dispose();
-    expect(api.getLiveCount(), 0);
+  test('objects in a map are disposed one by one', () {
+    final before = getLiveCount();
+    final journal = getResourceJournalMap();
+    expect(getLiveCount(), before + 2);
+    for (final resource in journal.resources.values) {
+      resource.dispose();
+    }
+    expect(getLiveCount(), before);
   });
 
-  test('EnumDecrementsLiveCount', () {
-    final maybeJournal = api.getMaybeResourceJournal();
-    expect(api.getLiveCount(), 2);
-    maybeJournal.dispose();
-    expect(api.getLiveCount(), 0);
+  test('objects in a map of lists are disposed one by one', () {
+    final before = getLiveCount();
+    final journal = getResourceJournalMapList();
+    expect(getLiveCount(), before + 2);
+    for (final resources in journal.resources.values) {
+      resources?.forEach((r) => r.dispose());
+    }
+    expect(getLiveCount(), before);
+  });
+
+  test('objects in an enum are disposed one by one', () {
+    final before = getLiveCount();
+    final journal = getMaybeResourceJournal() as SomeMaybeResourceJournal;
+    expect(getLiveCount(), before + 2);
+    for (final resource in journal.resource.resources) {
+      resource.dispose();
+    }
+    expect(getLiveCount(), before);
+  });
+
+  test('using disposes after the closure', () async {
+    final before = getLiveCount();
+    final resource = getResource();
+    expect(resource.using((r) => r.isDisposed), isFalse);
+    expect(resource.isDisposed, isTrue);
+    expect(getLiveCount(), before);
+
+    final failing = getResource();
+    expect(() => failing.using((_) => throw StateError('boom')), throwsStateError);
+    expect(failing.isDisposed, isTrue);
+
+    final later = getResource();
+    final result = later.using((r) async {
+      await Future<void>.delayed(Duration.zero);
+      return r.isDisposed;
+    });
+    expect(later.isDisposed, isFalse);
+    expect(await result, isFalse);
+    expect(later.isDisposed, isTrue);
+    expect(getLiveCount(), before);
   });
 }
@@ -0,0 +1,101 @@
+use std::sync::Mutex;
+
+/// A point on the plane.
+///
+/// Coordinates are in *pixels*, e.g.:
+///
+/// ```text
+/// let origin = Point { x: 0, y: 0 };
+/// ```
+#[derive(uniffi::Record)]
+pub struct Point {
+    /// Distance from the left edge.
+    pub x: i32,
+    /// Distance from the top edge. Comments like /* this */ stay intact.
+    pub y: i32,
+}
+
+/// How a shape is drawn.
+#[derive(uniffi::Enum)]
+pub enum Style {
+    /// Only the outline.
+    Stroke,
+    /// Filled in completely.
+    Fill,
+}
+
+/// Something that can be drawn.
+#[derive(uniffi::Enum)]
+pub enum Shape {
+    /// A single point.
+    Dot {
+        /// Where the dot is.
+        at: Point,
+    },
+    /// An empty shape.
+    Nothing,
+}
+
+/// Why drawing failed.
+#[derive(Debug, thiserror::Error, uniffi::Error)]
+pub enum DrawError {
+    /// The shape lies outside of the canvas.
+    #[error("out of bounds")]
+    OutOfBounds,
+}
+
+/// A surface to draw on.
+#[derive(uniffi::Object)]
+pub struct Canvas {
+    shapes: Mutex<Vec<Shape>>,
+}
+
+impl Default for Canvas {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[uniffi::export]
+impl Canvas {
+    /// Creates an empty canvas.
+    #[uniffi::constructor]
+    pub fn new() -> Self {
+        Self { shapes: Mutex::new(vec![]) }
+    }
+
+    /// Adds a shape, failing for points with negative coordinates.
+    ///
+    /// - Returns the number of shapes drawn so far.
+    pub fn draw(&self, shape: Shape) -> Result<u32, DrawError> {
+        if let Shape::Dot { at } = &shape {
+            if at.x < 0 || at.y < 0 {
+                return Err(DrawError::OutOfBounds);
+            }
+        }
+        let mut shapes = self.shapes.lock().unwrap();
+        shapes.push(shape);
+        Ok(shapes.len() as u32)
+    }
+}
+
+/// Receives every shape as it is drawn.
+#[uniffi::export(callback_interface)]
+pub trait Observer: Send + Sync {
+    /// Called with the [`Style`] used for the shape.
+    fn on_draw(&self, style: Style) -> bool;
+}
+
+/// Returns the point halfway between `a` and `b`.
+#[uniffi::export]
+pub fn midpoint(a: Point, b: Point) -> Point {
+    Point { x: (a.x + b.x) / 2, y: (a.y + b.y) / 2 }
+}
+
+/// Tells `observer` about a stroke.
+#[uniffi::export]
+pub fn notify(observer: Box<dyn Observer>) -> bool {
+    observer.on_draw(Style::Stroke)
+}
+
+uniffi::include_scaffolding!("api");
@@ -0,0 +1,56 @@
+import 'dart:io';
+
+import 'package:test/test.dart';
+import '../docstrings.dart';
+
+class RecordingObserver implements Observer {
+  final styles = <Style>[];
+
+  @override
+  bool onDraw(Style style) {
+    styles.add(style);
+    return true;
+  }
+}
+
+void main() {
+  final source = File('docstrings.dart').readAsStringSync();
+
+  void expectDocumented(String doc, String declaration) {
+    expect(source, matches(RegExp('${RegExp.escape(doc)}\\n\\s*${RegExp.escape(declaration)}')));
+  }
+
+  test('docstrings become doc comments', () {
+    expectDocumented('/// ```', '@immutable class Point');
+    expectDocumented('/// Distance from the left edge.', 'final int x;');
+    expectDocumented('/// Only the outline.', 'stroke,');
+    expectDocumented('/// Filled in completely.', 'fill,');
+    expectDocumented('/// A single point.', 'final class DotShape');
+    expectDocumented('/// Where the dot is.', 'final Point at;');
+    expectDocumented('/// The shape lies outside of the canvas.', 'final class OutOfBoundsDrawException');
+    expectDocumented('/// A surface to draw on.', 'class Canvas');
+    expectDocumented('/// Creates an empty canvas.', 'Canvas()');
+    expectDocumented('/// - Returns the number of shapes drawn so far.', 'int draw(');
+    expectDocumented('/// Receives every shape as it is drawn.', 'abstract class Observer');
+    expectDocumented('/// Called with the [`Style`] used for the shape.', 'bool onDraw(');
+    expectDocumented('/// Returns the point halfway between `a` and `b`.', 'Point midpoint(');
+  });
+
+  test('markdown is kept line by line', () {
+    expect(source, contains('/// A point on the plane.\n///\n/// Coordinates are in *pixels*, e.g.:\n'));
+    expect(source, contains('/// let origin = Point { x: 0, y: 0 };\n'));
+    expect(source, contains('/// Distance from the top edge. Comments like /* this */ stay intact.\n'));
+  });
+
+  test('documented declarations still work', () {
+    expect(midpoint(const Point(0, 0), const Point(4, 2)), const Point(2, 1));
+    final canvas = Canvas();
+    expect(canvas.draw(const NothingShape()), 1);
+    expect(canvas.draw(DotShape(const Point(1, 1))), 2);
+    expect(() => canvas.draw(DotShape(const Point(-1, 0))), throwsA(isA<OutOfBoundsDrawException>()));
+
+    final observer = RecordingObserver();
+    expect(notify(observer), isTrue);
+    expect(observer.styles, [Style.stroke]);
+  });
+}
@@ -0,0 +1,6 @@
+use anyhow::Result;
+
+#[test]
+fn docstrings() -> Result<()> {
+    uniffi_dart::testing::run_test("docstrings", "src/api.udl", None)
+}
@@ -1,5 +1,4 @@
 use core::time::Duration;
-use uniffi;
 
 #[uniffi::export]
 pub fn make_duration(seconds: u64, nanos: u32) -> Duration {
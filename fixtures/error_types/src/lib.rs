@@ -237,6 +237,31 @@ fn oops_tuple(i: u16) -> Result<(), TupleError> {
     }
 }
 
+// flat enum as an error, lifted with its `Display` message.
+#[derive(thiserror::Error, uniffi::Error, Debug)]
+#[uniffi(flat_error)]
+pub enum FlatError {
+    #[error("Too many: {0}")]
+    TooMany(u16),
+    #[error("Not found: {name}")]
+    NotFound { name: NonUniffiType },
+}
+
+#[uniffi::export]
+fn oops_flat(i: u16) -> Result<(), FlatError> {
+    if i == 0 {
+        Err(FlatError::TooMany(i))
+    } else if i == 1 {
+        Err(FlatError::NotFound {
+            name: NonUniffiType {
+                v: "flat".to_string(),
+            },
+        })
+    } else {
+        Ok(())
+    }
+}
+
 #[uniffi::export(default(t = None))]
 fn get_tuple(t: Option<TupleError>) -> TupleError {
     t.unwrap_or_else(|| TupleError::Oops("oops".to_string()))
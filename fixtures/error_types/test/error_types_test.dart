@@ -8,8 +8,7 @@ void main() {
         oops();
         fail('Must have failed');
       } on ErrorInterface catch (e) {
-        // TODO: Update when Display trait support is added
-        expect(e.toString(), 'ErrorInterface');
+        expect(e.toString(), 'because uniffi told me so\n\nCaused by:\n    oops');
         expect(e.chain().length, 2);
         expect(e.link(0), 'because uniffi told me so');
       }
@@ -20,27 +19,24 @@ void main() {
         oopsNowrap();
         fail('Must have failed');
       } on ErrorInterface catch (e) {
-        // TODO: Update when Display trait support is added
-        expect(e.toString(), 'ErrorInterface');
+        expect(e.toString(), 'because uniffi told me so\n\nCaused by:\n    oops');
         expect(e.chain().length, 2);
         expect(e.link(0), 'because uniffi told me so');
       }
     });
 
-    // TODO: Re-enable when trait interfaces are fully supported
-    // test('ErrorTrait implementation', () {
-    //   try {
-    //     toops();
-    //     fail('Must have failed');
-    //   } on ErrorTrait catch (e) {
-    //     expect(e.msg(), 'trait-oops');
-    //   }
-    // });
+    test('ErrorTrait implementation', () {
+      try {
+        toops();
+        fail('Must have failed');
+      } on ErrorTrait catch (e) {
+        expect(e.msg(), 'trait-oops');
+      }
+    });
 
     test('Get error instance', () {
       final e = getError('the error');
-      // TODO: Update when Display trait support is added
-      expect(e.toString(), 'ErrorInterface');
+      expect(e.toString(), 'the error');
       expect(e.link(0), 'the error');
     });
 
@@ -49,8 +45,18 @@ void main() {
         throwRich('oh no');
         fail('Must have failed');
       } on RichException catch (e) {
-        // TODO: Update when Display trait support is added
-        expect(e.toString(), 'RichException');
+        expect(e.toString(), 'RichError: "oh no"');
+      }
+    });
+
+    test('Proc-macro error interface uses Display', () {
+      expect(returnProcError('direct').toString(), 'ProcErrorInterface(direct)');
+      try {
+        throwProcError('thrown');
+        fail('Must have failed');
+      } on ProcErrorInterface catch (e) {
+        expect(e.toString(), 'ProcErrorInterface(thrown)');
+        expect(e.message(), 'thrown');
       }
     });
 
@@ -69,7 +75,7 @@ void main() {
         try {
           oopsEnum(1);
         } catch (e) {
-          expect(e.toString(), 'ValueErrorException');
+          expect(e.toString(), 'ValueErrorException(value: value)');
         }
       });
 
@@ -78,7 +84,7 @@ void main() {
         try {
           oopsEnum(2);
         } catch (e) {
-          expect(e.toString(), 'IntValueErrorException');
+          expect(e.toString(), 'IntValueErrorException(value: 2)');
         }
       });
 
@@ -87,7 +93,7 @@ void main() {
         try {
           oopsEnum(3);
         } catch (e) {
-          expect(e.toString(), 'FlatInnerExceptionErrorException');
+          expect(e.toString(), 'FlatInnerExceptionErrorException(error: CaseAFlatInner: inner)');
         }
       });
 
@@ -96,7 +102,7 @@ void main() {
         try {
           oopsEnum(4);
         } catch (e) {
-          expect(e.toString(), 'FlatInnerExceptionErrorException');
+          expect(e.toString(), 'FlatInnerExceptionErrorException(error: CaseBFlatInner: NonUniffiTypeValue: value)');
         }
       });
 
@@ -105,18 +111,38 @@ void main() {
         try {
           oopsEnum(5);
         } catch (e) {
-          expect(e.toString(), 'InnerExceptionErrorException');
+          expect(e.toString(), 'InnerExceptionErrorException(error: CaseAInner(inner))');
         }
       });
     });
 
+    group('Sealed Error Tests', () {
+      test('Catch a specific variant', () {
+        try {
+          oopsEnum(1);
+          fail('Must have failed');
+        } on ValueErrorException catch (e) {
+          expect(e.value, 'value');
+        }
+      });
+
+      test('Exhaustive switch over the variants', () {
+        String describe(TupleException e) => switch (e) {
+              OopsTupleException(:final v0) => 'oops: $v0',
+              ValueTupleException(:final v0) => 'value: $v0',
+            };
+        expect(describe(getTuple()), 'oops: oops');
+        expect(describe(ValueTupleException(3)), 'value: 3');
+      });
+    });
+
     group('Tuple Error Tests', () {
       test('TupleError Oops variant', () {
         expect(() => oopsTuple(0), throwsA(isA<TupleException>()));
         try {
           oopsTuple(0);
         } catch (e) {
-          expect(e.toString(), 'OopsTupleException');
+          expect(e.toString(), 'OopsTupleException(oops)');
         }
       });
 
@@ -125,26 +151,50 @@ void main() {
         try {
           oopsTuple(1);
         } catch (e) {
-          expect(e.toString(), 'ValueTupleException');
+          expect(e.toString(), 'ValueTupleException(1)');
         }
       });
 
       test('Get tuple with default', () {
-        final tuple = getTuple(null);
-        expect(tuple.toString(), 'OopsTupleException');
-        // Remove identity check as it compares instances, not values
-        // expect(getTuple(tuple), tuple);
+        final tuple = getTuple();
+        expect(tuple.toString(), 'OopsTupleException(oops)');
+        expect(getTuple(t: tuple), tuple);
+        expect(getTuple(t: ValueTupleException(4)), ValueTupleException(4));
       });
     });
 
-    // TODO: Investigate why aoops() isn't throwing an exception
-    // test('Async throw error', () async {
-    //   try {
-    //     await aoops();
-    //     fail('Must have failed');
-    //   } on ErrorInterface catch (e) {
-    //     expect(e.toString(), 'async-oops');
-    //   }
-    // });
+    group('Flat Error Tests', () {
+      test('FlatError TooMany variant', () {
+        expect(() => oopsFlat(0), throwsA(isA<TooManyFlatException>()));
+        try {
+          oopsFlat(0);
+        } on FlatException catch (e) {
+          expect(e.message, 'Too many: 0');
+          expect(e.toString(), 'TooManyFlatException: Too many: 0');
+        }
+      });
+
+      test('FlatError NotFound variant', () {
+        expect(() => oopsFlat(1), throwsA(isA<NotFoundFlatException>()));
+        try {
+          oopsFlat(1);
+        } on FlatException catch (e) {
+          expect(e.message, 'Not found: NonUniffiTypeValue: flat');
+        }
+      });
+
+      test('FlatError not thrown', () {
+        oopsFlat(2);
+      });
+    });
+
+    test('Async throw error', () async {
+      try {
+        await aoops();
+        fail('Must have failed');
+      } on ErrorInterface catch (e) {
+        expect(e.toString(), 'async-oops');
+      }
+    });
   });
 }
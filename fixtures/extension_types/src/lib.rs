@@ -0,0 +1,69 @@
+use std::collections::HashMap;
+
+pub struct Handle(pub i64);
+
+pub struct Score(pub f64);
+
+#[derive(PartialEq, Eq, Hash)]
+pub struct Label(pub String);
+
+uniffi::custom_newtype!(Handle, i64);
+uniffi::custom_newtype!(Score, f64);
+uniffi::custom_newtype!(Label, String);
+
+#[derive(uniffi::Record)]
+pub struct Entry {
+    pub handle: Handle,
+    pub label: Option<Label>,
+    pub scores: Vec<Score>,
+    pub by_label: HashMap<Label, Score>,
+}
+
+#[uniffi::export(callback_interface)]
+pub trait Scorer: Send + Sync {
+    fn score(&self, handle: Handle, label: Label) -> Score;
+    fn pick(&self, handles: Vec<Handle>) -> Option<Handle>;
+}
+
+#[uniffi::export]
+fn next_handle(handle: Handle) -> Handle {
+    Handle(handle.0 + 1)
+}
+
+#[uniffi::export]
+fn handles(count: i64) -> Vec<Handle> {
+    (0..count).map(Handle).collect()
+}
+
+#[uniffi::export]
+fn label_of(handle: Handle) -> Label {
+    Label(format!("#{}", handle.0))
+}
+
+#[uniffi::export]
+fn make_entry(handle: Handle, label: Option<Label>) -> Entry {
+    Entry {
+        scores: vec![Score(handle.0 as f64), Score(0.5)],
+        by_label: label.iter().map(|l| (Label(l.0.clone()), Score(1.0))).collect(),
+        handle,
+        label,
+    }
+}
+
+#[uniffi::export]
+fn total_score(entry: Entry) -> Score {
+    Score(entry.scores.iter().map(|s| s.0).sum::<f64>() + entry.by_label.values().map(|s| s.0).sum::<f64>())
+}
+
+#[uniffi::export]
+fn rank(scorer: Box<dyn Scorer>, handle: Handle) -> Score {
+    let label = Label(format!("#{}", handle.0));
+    scorer.score(handle, label)
+}
+
+#[uniffi::export]
+fn pick_from(scorer: Box<dyn Scorer>, count: i64) -> Option<Handle> {
+    scorer.pick((0..count).map(Handle).collect())
+}
+
+uniffi::include_scaffolding!("api");
@@ -0,0 +1,50 @@
+import 'dart:convert';
+
+import 'package:test/test.dart';
+import '../extension_types.dart';
+
+class LengthScorer implements Scorer {
+  @override
+  Score score(Handle handle, Label label) => Score(handle.value * 10.0 + label.value.length);
+
+  @override
+  Handle? pick(List<Handle> handles) => handles.isEmpty ? null : handles.last;
+}
+
+void main() {
+  test('custom types are wrapped in extension types', () {
+    final Handle next = nextHandle(const Handle(41));
+    expect(next, const Handle(42));
+    expect(next.value, 42);
+    expect(labelOf(const Handle(7)).value, '#7');
+    expect(handles(3), [const Handle(0), const Handle(1), const Handle(2)]);
+  });
+
+  test('records carry the wrapped types', () {
+    final entry = makeEntry(const Handle(3), const Label('three'));
+    expect(entry.handle, const Handle(3));
+    expect(entry.label, const Label('three'));
+    expect(entry.scores, [const Score(3.0), const Score(0.5)]);
+    expect(entry.byLabel, {const Label('three'): const Score(1.0)});
+    expect(totalScore(entry), const Score(4.5));
+    expect(totalScore(entry.copyWith(label: () => null, byLabel: {})), const Score(3.5));
+  });
+
+  test('callbacks receive and return the wrapped types', () {
+    expect(rank(LengthScorer(), const Handle(2)), const Score(22.0));
+    expect(pickFrom(LengthScorer(), 4), const Handle(3));
+    expect(pickFrom(LengthScorer(), 0), isNull);
+  });
+
+  test('JSON uses the builtin representation', () {
+    final entry = makeEntry(const Handle(3), const Label('three'));
+    final json = entry.toJson();
+    expect(json, {
+      'handle': 3,
+      'label': 'three',
+      'scores': [3.0, 0.5],
+      'by_label': {'three': 1.0},
+    });
+    expect(Entry.fromJson(jsonDecode(jsonEncode(json)) as Map<String, dynamic>), entry);
+  });
+}
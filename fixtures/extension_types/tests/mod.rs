@@ -0,0 +1,6 @@
+use anyhow::Result;
+
+#[test]
+fn extension_types() -> Result<()> {
+    uniffi_dart::testing::run_test("extension_types", "src/api.udl", Some("uniffi.toml"))
+}
@@ -0,0 +1,73 @@
+use std::sync::Arc;
+
+use external_types_lib::{Counter, Direction, MoveError, Point};
+
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct Route {
+    start: Point,
+    end: Point,
+    waypoints: Vec<Option<Point>>,
+}
+
+#[uniffi::export]
+pub fn midpoint(a: Point, b: Point) -> Point {
+    Point {
+        x: (a.x + b.x) / 2.0,
+        y: (a.y + b.y) / 2.0,
+    }
+}
+
+#[uniffi::export]
+pub fn make_route(start: Point, end: Point) -> Route {
+    let waypoints = vec![Some(midpoint(start.clone(), end.clone())), None];
+    Route {
+        start,
+        end,
+        waypoints,
+    }
+}
+
+#[uniffi::export]
+pub fn route_length(route: Route) -> f64 {
+    let dx = route.end.x - route.start.x;
+    let dy = route.end.y - route.start.y;
+    (dx * dx + dy * dy).sqrt()
+}
+
+#[uniffi::export]
+pub fn turn_right(direction: Direction) -> Direction {
+    match direction {
+        Direction::North => Direction::East,
+        Direction::East => Direction::South,
+        Direction::South => Direction::West,
+        Direction::West => Direction::North,
+    }
+}
+
+#[uniffi::export]
+pub fn step(point: Point, direction: Direction) -> Result<Point, MoveError> {
+    match direction {
+        Direction::North => Ok(Point {
+            x: point.x,
+            y: point.y + 1.0,
+        }),
+        Direction::East => Ok(Point {
+            x: point.x + 1.0,
+            y: point.y,
+        }),
+        _ => Err(MoveError::Blocked),
+    }
+}
+
+#[uniffi::export]
+pub fn count_twice(counter: Arc<Counter>) -> u32 {
+    counter.increment();
+    counter.increment()
+}
+
+#[uniffi::export]
+pub fn shared_counter() -> Arc<Counter> {
+    Arc::new(Counter::new())
+}
+
+uniffi::include_scaffolding!("api");
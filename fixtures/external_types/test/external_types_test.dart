@@ -0,0 +1,36 @@
+import 'package:test/test.dart';
+import '../external_types.dart';
+import 'package:uniffi_test/external_types_lib.dart' as lib;
+
+void main() {
+  test('external records as arguments and return values', () {
+    final mid = midpoint(lib.makePoint(0, 0), Point(2, 4));
+    expect(mid.x, 1);
+    expect(mid.y, 2);
+  });
+
+  test('external types nested in local records', () {
+    final route = makeRoute(Point(0, 0), Point(3, 4));
+    expect(route.waypoints.length, 2);
+    expect(route.waypoints[0]!.x, 1.5);
+    expect(route.waypoints[1], isNull);
+    expect(routeLength(route), 5);
+  });
+
+  test('external enums', () {
+    expect(turnRight(Direction.west), Direction.north);
+    expect(turnRight(lib.Direction.north), lib.Direction.east);
+  });
+
+  test('external errors', () {
+    expect(step(Point(0, 0), Direction.north).y, 1);
+    expect(() => step(Point(0, 0), Direction.south), throwsA(isA<MoveException>()));
+  });
+
+  test('external objects', () {
+    final counter = Counter();
+    expect(countTwice(counter), 2);
+    expect(counter.value(), 2);
+    expect(sharedCounter().increment(), 1);
+  });
+}
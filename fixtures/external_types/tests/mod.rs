@@ -0,0 +1,20 @@
+use anyhow::Result;
+
+#[test]
+fn external_types() -> Result<()> {
+    uniffi_dart::testing::run_library_test("external_types", "src/api.udl", Some("uniffi.toml"))
+}
+
+#[test]
+fn external_packages_are_imported_as_packages() -> Result<()> {
+    let bindings = uniffi_dart::testing::generate_library_bindings(
+        "external_types",
+        "src/api.udl",
+        Some("uniffi.toml"),
+        "external_types",
+    )?;
+    assert!(bindings.contains(
+        r#"import "package:uniffi_test/external_types_lib.dart" as external_types_lib;"#
+    ));
+    Ok(())
+}
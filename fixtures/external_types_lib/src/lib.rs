@@ -0,0 +1,51 @@
+// Types shared with the `external_types` fixture, which uses them in its own API.
+
+use std::sync::atomic::{AtomicU32, Ordering};
+
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct Point {
+    pub x: f64,
+    pub y: f64,
+}
+
+#[derive(Debug, Clone, uniffi::Enum)]
+pub enum Direction {
+    North,
+    East,
+    South,
+    West,
+}
+
+#[derive(Debug, thiserror::Error, uniffi::Error)]
+pub enum MoveError {
+    #[error("Blocked")]
+    Blocked,
+}
+
+#[derive(Debug, Default, uniffi::Object)]
+pub struct Counter {
+    value: AtomicU32,
+}
+
+#[uniffi::export]
+impl Counter {
+    #[uniffi::constructor]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn increment(&self) -> u32 {
+        self.value.fetch_add(1, Ordering::SeqCst) + 1
+    }
+
+    pub fn value(&self) -> u32 {
+        self.value.load(Ordering::SeqCst)
+    }
+}
+
+#[uniffi::export]
+pub fn make_point(x: f64, y: f64) -> Point {
+    Point { x, y }
+}
+
+uniffi::setup_scaffolding!();
@@ -0,0 +1,92 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::{Duration, SystemTime};
+
+#[derive(uniffi::Enum)]
+pub enum Role {
+    Admin,
+    Member,
+}
+
+#[derive(uniffi::Enum)]
+pub enum Contact {
+    Email { address: String },
+    Phone(String, Option<u16>),
+    Nowhere,
+}
+
+#[derive(uniffi::Record)]
+pub struct Address {
+    pub street: String,
+    pub zip: Option<u32>,
+}
+
+#[derive(uniffi::Record)]
+pub struct Profile {
+    pub user_id: u64,
+    pub name: String,
+    pub score: f64,
+    pub active: bool,
+    pub role: Role,
+    pub contacts: Vec<Contact>,
+    pub home: Option<Address>,
+    pub avatar: Vec<u8>,
+    pub joined: SystemTime,
+    pub session: Duration,
+    pub labels: HashMap<String, Vec<String>>,
+    pub ranks: HashMap<u32, Role>,
+    #[uniffi(default = None)]
+    pub nickname: Option<String>,
+}
+
+// Records holding objects have no JSON form
+#[derive(uniffi::Object)]
+pub struct Session;
+
+#[derive(uniffi::Record)]
+pub struct Login {
+    pub session: Arc<Session>,
+}
+
+#[uniffi::export]
+fn sample_profile() -> Profile {
+    Profile {
+        user_id: 42,
+        name: "Ada".into(),
+        score: 9.5,
+        active: true,
+        role: Role::Admin,
+        contacts: vec![
+            Contact::Email { address: "ada@example.com".into() },
+            Contact::Phone("555-0100".into(), Some(44)),
+            Contact::Nowhere,
+        ],
+        home: Some(Address { street: "1 Loop Rd".into(), zip: None }),
+        avatar: vec![0, 1, 2, 254, 255],
+        joined: SystemTime::UNIX_EPOCH + Duration::from_secs(1_700_000_000),
+        session: Duration::from_millis(1500),
+        labels: HashMap::from([("team".into(), vec!["core".into(), "ffi".into()])]),
+        ranks: HashMap::from([(1, Role::Admin), (2, Role::Member)]),
+        nickname: None,
+    }
+}
+
+#[uniffi::export]
+fn describe_profile(profile: Profile) -> String {
+    format!(
+        "{} #{} contacts={} avatar={} session={}ms ranks={}",
+        profile.name,
+        profile.user_id,
+        profile.contacts.len(),
+        profile.avatar.len(),
+        profile.session.as_millis(),
+        profile.ranks.len(),
+    )
+}
+
+#[uniffi::export]
+fn login() -> Login {
+    Login { session: Arc::new(Session) }
+}
+
+uniffi::include_scaffolding!("api");
@@ -0,0 +1,48 @@
+import 'dart:convert';
+
+import 'package:test/test.dart';
+import '../json_types.dart';
+
+void main() {
+  test('records round-trip through JSON text', () {
+    final profile = sampleProfile();
+    final decoded = Profile.fromJson(jsonDecode(jsonEncode(profile.toJson())) as Map<String, dynamic>);
+    expect(decoded, profile);
+    expect(describeProfile(decoded),
+        'Ada #42 contacts=3 avatar=5 session=1500ms ranks=2');
+  });
+
+  test('values are encoded consistently', () {
+    final json = sampleProfile().toJson();
+    expect(json['user_id'], 42);
+    expect(json['role'], {'type': 'Admin'});
+    expect(json['home'], {'street': '1 Loop Rd', 'zip': null});
+    expect(json['avatar'], base64Encode([0, 1, 2, 254, 255]));
+    expect(json['joined'], '2023-11-14T22:13:20.000Z');
+    expect(json['session'], 1500000);
+    expect(json['labels'], {
+      'team': ['core', 'ffi']
+    });
+    expect(json['ranks'], unorderedEquals([
+      [1, {'type': 'Admin'}],
+      [2, {'type': 'Member'}],
+    ]));
+    expect(json['nickname'], isNull);
+  });
+
+  test('enums are tagged with their variant', () {
+    expect(EmailContact('ada@example.com').toJson(), {'type': 'Email', 'address': 'ada@example.com'});
+    expect(PhoneContact('555', 44).toJson(), {'type': 'Phone', 'v0': '555', 'v1': 44});
+    expect(NowhereContact().toJson(), {'type': 'Nowhere'});
+
+    expect(Contact.fromJson({'type': 'Phone', 'v0': '555', 'v1': null}), PhoneContact('555', null));
+    expect(Role.fromJson({'type': 'Member'}), Role.member);
+    expect(() => Role.fromJson({'type': 'Guest'}), throwsArgumentError);
+    expect(() => Contact.fromJson({'type': 'Fax'}), throwsArgumentError);
+  });
+
+  test('defaulted fields are read back', () {
+    final json = sampleProfile().copyWith(nickname: () => 'ada').toJson();
+    expect(Profile.fromJson(json).nickname, 'ada');
+  });
+}
@@ -0,0 +1,6 @@
+use anyhow::Result;
+
+#[test]
+fn json_types() -> Result<()> {
+    uniffi_dart::testing::run_test("json_types", "src/api.udl", Some("uniffi.toml"))
+}
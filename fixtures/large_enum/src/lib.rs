@@ -1,4 +1,5 @@
-use uniffi;
+use std::collections::HashMap;
+
 use uniffi::{Enum, Record};
 
 #[uniffi::export]
@@ -31,6 +32,39 @@ pub fn take_flat_enum(flat: FlatEnum) -> String {
     }
 }
 
+#[uniffi::export]
+pub fn all_flat_enums() -> Vec<FlatEnum> {
+    vec![FlatEnum::One, FlatEnum::Two, FlatEnum::Three, FlatEnum::Four]
+}
+
+#[uniffi::export]
+pub fn maybe_flat_enum(flat: Option<FlatEnum>) -> Option<FlatEnum> {
+    flat
+}
+
+#[uniffi::export]
+pub fn new_flat_enum_holder() -> FlatEnumHolder {
+    FlatEnumHolder {
+        flat: FlatEnum::Two,
+        maybe: None,
+        all: all_flat_enums(),
+        by_name: HashMap::from([
+            ("three".to_string(), FlatEnum::Three),
+            ("four".to_string(), FlatEnum::Four),
+        ]),
+    }
+}
+
+#[uniffi::export]
+pub fn echo_flat_enum_holder(holder: FlatEnumHolder) -> FlatEnumHolder {
+    holder
+}
+
+#[uniffi::export]
+pub fn new_flat_value(value: FlatEnum) -> Value {
+    Value::Flat { value }
+}
+
 #[uniffi::export]
 pub fn new_u8_value(value: u8) -> Value {
     Value::U8 { value }
@@ -108,10 +142,15 @@ pub fn new_public_key_value(value: Vec<i32>) -> Value {
 //      todo!("Not done")
 // }
 
+#[uniffi::export]
+pub fn echo_values(values: Vec<Value>) -> Vec<Value> {
+    values
+}
+
 #[uniffi::export]
 pub fn take_value(value: Value) -> String {
     match value {
-        Value::String { value } => format!("{}", value),
+        Value::String { value } => value.to_string(),
         Value::Bool { value } => format!("{}", value),
         Value::U8 { value } => format!("{}", value),
         Value::U16 { value } => format!("{}", value),
@@ -128,6 +167,7 @@ pub fn take_value(value: Value) -> String {
         // Value::HomogenousCollection { elements } => format!("{:?}", elements),
         // Value::Map { entries } => format!("{:?}", entries),
         Value::PublicKey { value } => format!("{:?}", value),
+        Value::Flat { value } => format!("{:?}", value),
     }
 }
 
@@ -139,6 +179,110 @@ pub enum FlatEnum {
     Four,
 }
 
+// Flat enums with explicit discriminants, exposed to Dart as `value`
+#[derive(Debug, Clone, Copy, PartialEq, Enum)]
+#[repr(u8)]
+pub enum Priority {
+    Low = 10,
+    Medium = 15,
+    High = 20,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Enum)]
+#[repr(i16)]
+pub enum Level {
+    Below = -1,
+    Even,
+    Above = 5,
+}
+
+#[uniffi::export]
+pub fn raise_priority(priority: Priority) -> Priority {
+    match priority {
+        Priority::Low => Priority::Medium,
+        Priority::Medium | Priority::High => Priority::High,
+    }
+}
+
+#[uniffi::export]
+pub fn priority_code(priority: Priority) -> u8 {
+    priority as u8
+}
+
+#[uniffi::export]
+pub fn all_levels() -> Vec<Level> {
+    vec![Level::Below, Level::Even, Level::Above]
+}
+
+#[uniffi::export]
+pub fn level_code(level: Level) -> i16 {
+    level as i16
+}
+
+// Non-exhaustive enums, which get an unknown case for variants added later on
+#[derive(Debug, Clone, Copy, PartialEq, Enum)]
+#[non_exhaustive]
+pub enum Channel {
+    Stable,
+    Beta,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Enum)]
+#[non_exhaustive]
+#[repr(u16)]
+pub enum StatusCode {
+    Ok = 200,
+    NotFound = 404,
+}
+
+#[derive(Debug, Clone, PartialEq, Enum)]
+#[non_exhaustive]
+pub enum Event {
+    Started { at: u32 },
+    Stopped,
+}
+
+#[uniffi::export]
+pub fn echo_channel(channel: Channel) -> Channel {
+    channel
+}
+
+#[uniffi::export]
+pub fn echo_status_code(code: StatusCode) -> StatusCode {
+    code
+}
+
+#[uniffi::export]
+pub fn echo_event(event: Event) -> Event {
+    event
+}
+
+#[derive(Debug, Clone, PartialEq, uniffi::Record)]
+pub struct EventLog {
+    pub last: Event,
+    pub channels: Vec<Channel>,
+}
+
+#[uniffi::export]
+pub fn echo_maybe_event(event: Option<Event>) -> Option<Event> {
+    event
+}
+
+#[uniffi::export]
+pub fn echo_events_by_name(events: HashMap<String, Event>) -> HashMap<String, Event> {
+    events
+}
+
+#[uniffi::export]
+pub fn echo_events(events: Vec<Event>) -> Vec<Event> {
+    events
+}
+
+#[uniffi::export]
+pub fn echo_event_log(log: EventLog) -> EventLog {
+    log
+}
+
 // TODO: Add Collections (Maps, Vector, ...)
 #[derive(Debug, Clone, Enum)]
 pub enum Value {
@@ -166,6 +310,15 @@ pub enum Value {
     //     entries: Vec<MapEntry>,
     // },
     PublicKey { value: Vec<i32> },
+    Flat { value: FlatEnum },
+}
+
+#[derive(Clone, Debug, Record)]
+pub struct FlatEnumHolder {
+    pub flat: FlatEnum,
+    pub maybe: Option<FlatEnum>,
+    pub all: Vec<FlatEnum>,
+    pub by_name: HashMap<String, FlatEnum>,
 }
 
 #[derive(Clone, Debug, Record)]
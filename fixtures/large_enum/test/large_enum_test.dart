@@ -1,3 +1,4 @@
+import 'dart:typed_data';
 import 'package:test/test.dart';
 import '../large_enum.dart';
 
@@ -100,4 +101,148 @@ void main() {
 
     expect(takeValue(publicKeyValue), inner_list.toString());
   });
+
+  test('Complex enums inside sequences', () {
+    final values = [StringValue("first"), U8Value(7), PublicKeyValue([1, 2, 3]), BoolValue(true)];
+    expect(echoValues(values), values);
+
+    // Variants report the bytes they take up, including their index, wherever they sit in a buffer
+    final buf = Uint8List(64);
+    final written = FfiConverterValue.write(values[2], Uint8List.sublistView(buf, 10));
+    expect(written, FfiConverterValue.allocationSize(values[2]));
+    final read = FfiConverterValue.read(Uint8List.sublistView(buf, 10));
+    expect(read.bytesRead, written);
+    expect(read.value, values[2]);
+  });
+
+  test('Flat enums inside sequences, optionals, records and maps', () {
+    expect(allFlatEnums(), [FlatEnum.one, FlatEnum.two, FlatEnum.three, FlatEnum.four]);
+    expect(maybeFlatEnum(FlatEnum.three), FlatEnum.three);
+    expect(maybeFlatEnum(null), null);
+
+    final holder = newFlatEnumHolder();
+    expect(holder.flat, FlatEnum.two);
+    expect(holder.maybe, null);
+    expect(holder.all.length, 4);
+    expect(holder.byName["three"], FlatEnum.three);
+
+    final echoed = echoFlatEnumHolder(FlatEnumHolder(
+        FlatEnum.four, FlatEnum.one, [FlatEnum.two, FlatEnum.two], {"one": FlatEnum.one}));
+    expect(echoed.flat, FlatEnum.four);
+    expect(echoed.maybe, FlatEnum.one);
+    expect(echoed.all, [FlatEnum.two, FlatEnum.two]);
+    expect(echoed.byName, {"one": FlatEnum.one});
+  });
+
+  test('Flat enums inside data enums', () {
+    final value = newFlatValue(FlatEnum.three) as FlatValue;
+    expect(value.value, FlatEnum.three);
+    expect(takeValue(value), "Three");
+    expect(takeValue(FlatValue(FlatEnum.one)), "One");
+  });
+
+  test('Flat enums with explicit discriminants', () {
+    expect(Priority.low.value, 10);
+    expect(Priority.fromValue(20), Priority.high);
+    expect(() => Priority.fromValue(11), throwsArgumentError);
+    expect(raisePriority(Priority.low), Priority.medium);
+    expect(priorityCode(Priority.high), Priority.high.value);
+
+    expect(allLevels(), [Level.below, Level.even, Level.above]);
+    expect(allLevels().map((l) => l.value), [-1, 0, 5]);
+    expect(levelCode(Level.fromValue(5)), 5);
+  });
+
+  test('Flat enums without explicit discriminants stay plain', () {
+    expect(FlatEnum.values.length, 4);
+    expect(FlatEnum.two.index, 1);
+  });
+
+  test('Non-exhaustive enums lift unknown variants', () {
+    expect(echoChannel(Channel.beta), Channel.beta);
+    expect(echoStatusCode(StatusCode.notFound).value, 404);
+    expect(const StatusCode.unknown(9).value, null);
+    expect((echoEvent(StartedEvent(5)) as StartedEvent).at, 5);
+    expect(echoEvent(const StoppedEvent()), isA<StoppedEvent>());
+
+    // A variant added by a newer version of the library
+    final newer = Uint8List(4)..buffer.asByteData().setInt32(0, 7);
+    final channel = FfiConverterChannel.read(newer).value;
+    expect(channel, const Channel.unknown(7));
+    expect(channel.isUnknown, isTrue);
+    expect(channel.toString(), 'Channel.unknown(7)');
+    expect(FfiConverterStatusCode.read(newer).value.rawIndex, 7);
+
+    final event = FfiConverterEvent.read(newer).value as UnknownEvent;
+    expect(event.rawIndex, 7);
+    expect(event.toString(), 'UnknownEvent(7)');
+    final lowered = Uint8List(event.allocationSize());
+    event.write(lowered);
+    expect(lowered, newer);
+  });
+
+  test('Unknown flat variants round-trip with their raw index', () {
+    expect(Channel.beta.rawIndex, 2);
+    expect(Channel.beta.isUnknown, isFalse);
+    expect(Channel.values, [Channel.stable, Channel.beta]);
+    expect(FfiConverterChannel.lift(FfiConverterChannel.lower(const Channel.unknown(7))), const Channel.unknown(7));
+    expect(FfiConverterStatusCode.lift(FfiConverterStatusCode.lower(const StatusCode.unknown(9))).rawIndex, 9);
+
+    final log = EventLog(const StoppedEvent(), [Channel.beta, const Channel.unknown(5)]);
+    expect(FfiConverterEventLog.lift(FfiConverterEventLog.lower(log)), log);
+    expect(echoEventLog(EventLog(StartedEvent(3), [Channel.beta])).channels, [Channel.beta]);
+    expect(echoEvents([const StoppedEvent()]), [isA<StoppedEvent>()]);
+  });
+
+  test('Unknown data variants nested in other values', () {
+    // [StoppedEvent, variant 7] as a sequence, the unknown variant being last
+    final lastInSequence = Uint8List(12)
+      ..buffer.asByteData().setInt32(0, 2)
+      ..buffer.asByteData().setInt32(4, 2)
+      ..buffer.asByteData().setInt32(8, 7);
+    final events = FfiConverterSequenceEvent.read(lastInSequence);
+    expect(events.bytesRead, 12);
+    expect(events.value[0], isA<StoppedEvent>());
+    expect((events.value[1] as UnknownEvent).rawIndex, 7);
+
+    // [variant 7, StoppedEvent]: the unknown variant's fields can't be told apart from the rest
+    final firstInSequence = Uint8List(12)
+      ..buffer.asByteData().setInt32(0, 2)
+      ..buffer.asByteData().setInt32(4, 7)
+      ..buffer.asByteData().setInt32(8, 2);
+    expect(() => FfiConverterSequenceEvent.read(firstInSequence), throwsA(isA<UniffiInternalError>()));
+
+    // EventLog(last: variant 7, channels: [])
+    final inRecord = Uint8List(8)..buffer.asByteData().setInt32(0, 7);
+    expect(() => FfiConverterEventLog.read(inRecord), throwsA(isA<UniffiInternalError>()));
+
+    // Some(variant 7), with nothing after it
+    final inOptional = Uint8List(5)
+      ..[0] = 1
+      ..buffer.asByteData().setInt32(1, 7);
+    final maybe = FfiConverterOptionalEvent.read(inOptional);
+    expect(maybe.bytesRead, 5);
+    expect((maybe.value as UnknownEvent).rawIndex, 7);
+
+    // {"a": variant 7, "b": StoppedEvent}
+    final inMap = Uint8List(22)
+      ..buffer.asByteData().setInt32(0, 2)
+      ..buffer.asByteData().setInt32(4, 1)
+      ..[8] = 0x61
+      ..buffer.asByteData().setInt32(9, 7)
+      ..buffer.asByteData().setInt32(13, 1)
+      ..[17] = 0x62
+      ..buffer.asByteData().setInt32(18, 2);
+    expect(() => FfiConverterMapStringEvent.read(inMap), throwsA(isA<UniffiInternalError>()));
+
+    // Unknown flat variants always take up 4 bytes, so they can be read anywhere
+    final log = Uint8List(16)
+      ..buffer.asByteData().setInt32(0, 2)
+      ..buffer.asByteData().setInt32(4, 2)
+      ..buffer.asByteData().setInt32(8, 9)
+      ..buffer.asByteData().setInt32(12, 1);
+    final lifted = FfiConverterEventLog.read(log);
+    expect(lifted.bytesRead, 16);
+    expect(lifted.value.channels, [const Channel.unknown(9), Channel.stable]);
+  });
 }
@@ -0,0 +1,131 @@
+use std::collections::HashMap;
+
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct Book {
+    title: String,
+    pages: u32,
+}
+
+#[derive(Debug, Clone, uniffi::Enum)]
+pub enum Binding {
+    Paperback,
+    Hardcover { weight: u32 },
+}
+
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct Library {
+    name: String,
+    books: HashMap<String, Book>,
+    bindings: HashMap<String, Vec<Option<Binding>>>,
+}
+
+#[derive(Debug, Clone, uniffi::Enum)]
+pub enum Shelf {
+    Empty,
+    Filled { books: HashMap<String, Book> },
+}
+
+#[uniffi::export(callback_interface)]
+pub trait MapVisitor: Send + Sync {
+    fn visit(&self, counts: HashMap<String, i32>) -> String;
+}
+
+fn sample_books() -> HashMap<String, Book> {
+    HashMap::from([
+        (
+            "dune".to_string(),
+            Book {
+                title: "Dune".to_string(),
+                pages: 412,
+            },
+        ),
+        (
+            "emma".to_string(),
+            Book {
+                title: "Emma".to_string(),
+                pages: 474,
+            },
+        ),
+    ])
+}
+
+#[uniffi::export]
+pub fn get_books() -> HashMap<String, Book> {
+    sample_books()
+}
+
+#[uniffi::export]
+pub fn count_pages(books: HashMap<String, Book>) -> u32 {
+    books.values().map(|b| b.pages).sum()
+}
+
+#[uniffi::export]
+pub fn echo_int_keys(map: HashMap<i32, String>) -> HashMap<i32, String> {
+    map
+}
+
+#[uniffi::export]
+pub fn echo_optional_values(map: HashMap<String, Option<u64>>) -> HashMap<String, Option<u64>> {
+    map
+}
+
+#[uniffi::export]
+pub fn echo_nested(
+    map: HashMap<String, Vec<Option<Binding>>>,
+) -> HashMap<String, Vec<Option<Binding>>> {
+    map
+}
+
+#[uniffi::export]
+pub fn get_library() -> Library {
+    Library {
+        name: "City".to_string(),
+        books: sample_books(),
+        bindings: HashMap::from([
+            (
+                "dune".to_string(),
+                vec![Some(Binding::Paperback), None, Some(Binding::Hardcover { weight: 900 })],
+            ),
+            ("emma".to_string(), vec![]),
+        ]),
+    }
+}
+
+#[uniffi::export]
+pub fn echo_library(library: Library) -> Library {
+    library
+}
+
+#[uniffi::export]
+pub fn get_shelf(empty: bool) -> Shelf {
+    if empty {
+        Shelf::Empty
+    } else {
+        Shelf::Filled {
+            books: sample_books(),
+        }
+    }
+}
+
+#[uniffi::export]
+pub fn count_shelf(shelf: Shelf) -> u32 {
+    match shelf {
+        Shelf::Empty => 0,
+        Shelf::Filled { books } => books.len() as u32,
+    }
+}
+
+#[uniffi::export]
+pub fn visit_counts(visitor: Box<dyn MapVisitor>) -> String {
+    visitor.visit(HashMap::from([
+        ("one".to_string(), 1),
+        ("two".to_string(), 2),
+    ]))
+}
+
+#[uniffi::export]
+pub async fn get_books_async() -> HashMap<String, Book> {
+    sample_books()
+}
+
+uniffi::include_scaffolding!("api");
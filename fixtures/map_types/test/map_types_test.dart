@@ -0,0 +1,80 @@
+import 'package:test/test.dart';
+import '../map_types.dart';
+
+class CountingVisitor extends MapVisitor {
+  @override
+  String visit(Map<String, int> counts) {
+    final keys = counts.keys.toList()..sort();
+    return keys.map((k) => "$k=${counts[k]}").join(",");
+  }
+}
+
+void main() {
+  test('lifting a map of records', () {
+    final books = getBooks();
+    expect(books.length, 2);
+    expect(books["dune"]!.title, "Dune");
+    expect(books["dune"]!.pages, 412);
+    expect(books["emma"]!.pages, 474);
+  });
+
+  test('lowering a map of records', () {
+    expect(countPages({}), 0);
+    expect(countPages({"a": Book("A", 10), "b": Book("B", 32)}), 42);
+    expect(countPages(getBooks()), 886);
+  });
+
+  test('maps with non-string keys and optional values', () {
+    final ints = echoIntKeys({1: "one", -2: "minus two", 300: ""});
+    expect(ints, {1: "one", -2: "minus two", 300: ""});
+
+    final optionals = echoOptionalValues({"some": 42, "none": null});
+    expect(optionals.length, 2);
+    expect(optionals["some"], 42);
+    expect(optionals.containsKey("none"), true);
+    expect(optionals["none"], null);
+  });
+
+  test('nested map values', () {
+    final nested = echoNested({
+      "mixed": [PaperbackBinding(), null, HardcoverBinding(7)],
+      "empty": [],
+    });
+    expect(nested["empty"], isEmpty);
+    final mixed = nested["mixed"]!;
+    expect(mixed.length, 3);
+    expect(mixed[0], isA<PaperbackBinding>());
+    expect(mixed[1], null);
+    expect((mixed[2] as HardcoverBinding).weight, 7);
+  });
+
+  test('maps inside records', () {
+    final library = getLibrary();
+    expect(library.name, "City");
+    expect(library.books.keys.toSet(), {"dune", "emma"});
+    expect(library.bindings["emma"], isEmpty);
+    expect(library.bindings["dune"]!.length, 3);
+    expect((library.bindings["dune"]![2] as HardcoverBinding).weight, 900);
+
+    final echoed = echoLibrary(library);
+    expect(echoed.books["emma"]!.title, "Emma");
+    expect(echoed.bindings["dune"]![1], null);
+  });
+
+  test('maps inside enum variants', () {
+    expect(getShelf(true), isA<EmptyShelf>());
+    final shelf = getShelf(false) as FilledShelf;
+    expect(shelf.books["dune"]!.title, "Dune");
+    expect(countShelf(shelf), 2);
+    expect(countShelf(FilledShelf({})), 0);
+  });
+
+  test('maps as callback arguments', () {
+    expect(visitCounts(CountingVisitor()), "one=1,two=2");
+  });
+
+  test('maps as async return values', () async {
+    final books = await getBooksAsync();
+    expect(books["emma"]!.title, "Emma");
+  });
+}
@@ -0,0 +1,6 @@
+use anyhow::Result;
+
+#[test]
+fn map_types() -> Result<()> {
+    uniffi_dart::testing::run_test("map_types", "src/api.udl", None)
+}
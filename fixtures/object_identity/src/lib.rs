@@ -0,0 +1,56 @@
+use std::sync::{Arc, Mutex};
+
+#[derive(uniffi::Object)]
+pub struct Item {
+    label: String,
+}
+
+#[uniffi::export]
+impl Item {
+    #[uniffi::constructor]
+    fn new(label: String) -> Self {
+        Self { label }
+    }
+
+    fn label(&self) -> String {
+        self.label.clone()
+    }
+}
+
+#[derive(uniffi::Object)]
+pub struct Shelf {
+    items: Mutex<Vec<Arc<Item>>>,
+}
+
+#[uniffi::export]
+impl Shelf {
+    #[uniffi::constructor]
+    fn new(labels: Vec<String>) -> Self {
+        Self {
+            items: Mutex::new(labels.into_iter().map(|label| Arc::new(Item { label })).collect()),
+        }
+    }
+
+    fn put(&self, item: Arc<Item>) {
+        self.items.lock().unwrap().push(item);
+    }
+
+    fn get(&self, index: u32) -> Option<Arc<Item>> {
+        self.items.lock().unwrap().get(index as usize).cloned()
+    }
+
+    fn items(&self) -> Vec<Arc<Item>> {
+        self.items.lock().unwrap().clone()
+    }
+
+    /// How many references to the item at `index` are held, including the shelf's own.
+    fn references(&self, index: u32) -> u32 {
+        Arc::strong_count(&self.items.lock().unwrap()[index as usize]) as u32
+    }
+
+    async fn get_later(&self, index: u32) -> Option<Arc<Item>> {
+        self.get(index)
+    }
+}
+
+uniffi::include_scaffolding!("api");
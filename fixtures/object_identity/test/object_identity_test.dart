@@ -0,0 +1,56 @@
+import 'dart:ffi';
+
+import 'package:test/test.dart';
+import '../object_identity.dart';
+
+void main() {
+  test('the same Rust object lifts to the same Dart instance', () async {
+    final shelf = Shelf(['a', 'b']);
+    final first = shelf.get_(0)!;
+    expect(identical(shelf.get_(0), first), isTrue);
+    expect(identical(shelf.items().first, first), isTrue);
+    expect(identical(await shelf.getLater(0), first), isTrue);
+    expect(identical(shelf.get_(1), first), isFalse);
+    expect(shelf.get_(1)!.label(), 'b');
+  });
+
+  test('repeated lifts release their extra reference', () {
+    final shelf = Shelf(['a']);
+    final item = shelf.get_(0)!;
+    for (var i = 0; i < 5; i++) {
+      shelf.get_(0);
+      shelf.items();
+    }
+    // One reference held by the shelf and one by `item`
+    expect(shelf.references(0), 2);
+    expect(item.label(), 'a');
+  });
+
+  test('objects created in Dart come back as themselves', () {
+    final shelf = Shelf([]);
+    final item = Item('mine');
+    shelf.put(item);
+    expect(identical(shelf.get_(0), item), isTrue);
+
+    final cache = {item: 'cached'};
+    expect(cache[shelf.get_(0)], 'cached');
+  });
+
+  test('disposed objects are lifted into new instances', () {
+    final shelf = Shelf(['a']);
+    final item = shelf.get_(0)!;
+    item.dispose();
+    expect(shelf.references(0), 1);
+
+    final again = shelf.get_(0)!;
+    expect(identical(again, item), isFalse);
+    expect(again.label(), 'a');
+    expect(identical(shelf.get_(0), again), isTrue);
+  });
+
+  test('objects are kept alive across FFI calls', () {
+    final shelf = Shelf(['a']);
+    expect(shelf, isA<Finalizable>());
+    expect(shelf.get_(0), isA<Finalizable>());
+  });
+}
@@ -0,0 +1,6 @@
+use anyhow::Result;
+
+#[test]
+fn object_identity() -> Result<()> {
+    uniffi_dart::testing::run_test("object_identity", "src/api.udl", Some("uniffi.toml"))
+}
@@ -0,0 +1,120 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::{Arc, Mutex};
+
+/// Counts up to a limit.
+#[derive(uniffi::Object)]
+pub struct Counter {
+    name: String,
+    limit: u32,
+    value: Mutex<u32>,
+}
+
+#[uniffi::export]
+impl Counter {
+    #[uniffi::constructor]
+    fn new(name: String, limit: u32) -> Self {
+        Self {
+            name,
+            limit,
+            value: Mutex::new(0),
+        }
+    }
+
+    fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn value(&self) -> u32 {
+        *self.value.lock().unwrap()
+    }
+
+    /// Counts one up, failing once the limit is reached.
+    fn increment(&self) -> Result<u32, Arc<Overflow>> {
+        let mut value = self.value.lock().unwrap();
+        if *value >= self.limit {
+            return Err(Arc::new(Overflow { limit: self.limit }));
+        }
+        *value += 1;
+        Ok(*value)
+    }
+
+    /// A new counter starting where this one is.
+    fn fork(&self, name: String) -> Arc<Counter> {
+        Arc::new(Counter {
+            name,
+            limit: self.limit,
+            value: Mutex::new(self.value()),
+        })
+    }
+
+    async fn fork_later(&self, name: String) -> Arc<Counter> {
+        self.fork(name)
+    }
+}
+
+#[derive(Debug, uniffi::Object)]
+pub struct Overflow {
+    limit: u32,
+}
+
+#[uniffi::export]
+impl Overflow {
+    fn limit(&self) -> u32 {
+        self.limit
+    }
+}
+
+impl fmt::Display for Overflow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "limit of {} reached", self.limit)
+    }
+}
+
+impl std::error::Error for Overflow {}
+
+#[derive(uniffi::Object, Default)]
+pub struct Registry {
+    counters: Mutex<HashMap<String, Arc<Counter>>>,
+}
+
+#[uniffi::export]
+impl Registry {
+    #[uniffi::constructor]
+    fn new() -> Self {
+        Self::default()
+    }
+
+    fn add(&self, counter: Arc<Counter>) {
+        self.counters
+            .lock()
+            .unwrap()
+            .insert(counter.name(), counter);
+    }
+
+    fn get(&self, name: String) -> Option<Arc<Counter>> {
+        self.counters.lock().unwrap().get(&name).cloned()
+    }
+
+    fn all(&self) -> Vec<Arc<Counter>> {
+        let mut counters: Vec<_> = self.counters.lock().unwrap().values().cloned().collect();
+        counters.sort_by_key(|c| c.name());
+        counters
+    }
+
+    fn by_name(&self) -> HashMap<String, Arc<Counter>> {
+        self.counters.lock().unwrap().clone()
+    }
+}
+
+#[uniffi::export]
+fn make_counter(name: String, limit: u32) -> Arc<Counter> {
+    Arc::new(Counter::new(name, limit))
+}
+
+#[uniffi::export]
+fn total(counters: Vec<Arc<Counter>>) -> u32 {
+    counters.iter().map(|c| c.value()).sum()
+}
+
+uniffi::include_scaffolding!("api");
@@ -0,0 +1,84 @@
+import 'package:test/test.dart';
+import '../object_interfaces.dart';
+
+class FakeCounter implements CounterInterface {
+  FakeCounter(this._name, [this._value = 0]);
+
+  final String _name;
+  int _value;
+  bool disposed = false;
+
+  @override
+  String name() => _name;
+
+  @override
+  int value() => _value;
+
+  @override
+  int increment() => ++_value;
+
+  @override
+  CounterInterface fork(String name) => FakeCounter(name, _value);
+
+  @override
+  Future<CounterInterface> forkLater(String name) async => fork(name);
+
+  @override
+  bool get isDisposed => disposed;
+
+  @override
+  void dispose() => disposed = true;
+
+  @override
+  R using<R>(R Function(CounterInterface value) action) {
+    try {
+      return action(this);
+    } finally {
+      dispose();
+    }
+  }
+}
+
+String describe(CounterInterface counter) => '${counter.name()}=${counter.value()}';
+
+void main() {
+  test('Dart fakes stand in for objects', () async {
+    final fake = FakeCounter('fake');
+    fake.increment();
+    expect(describe(fake), 'fake=1');
+    expect(describe(fake.fork('copy')), 'copy=1');
+    expect(describe(await fake.forkLater('later')), 'later=1');
+    expect(fake.using(describe), 'fake=1');
+    expect(fake.isDisposed, isTrue);
+  });
+
+  test('Rust objects are used through their interface', () async {
+    final CounterInterface counter = makeCounter('clicks', 2);
+    expect(counter, isA<Counter>());
+    expect(counter.increment(), 1);
+    expect(describe(counter), 'clicks=1');
+
+    final fork = counter.fork('fork');
+    expect(fork.increment(), 2);
+    expect(describe(await counter.forkLater('later')), 'later=1');
+
+    expect(() => fork.increment(), throwsA(isA<Overflow>().having((e) => e.limit(), 'limit', 2)));
+    expect(fork.increment, throwsA(isA<OverflowInterface>()));
+
+    expect(fork.using((CounterInterface c) => c.value()), 2);
+    expect(fork.isDisposed, isTrue);
+  });
+
+  test('returned collections are typed against the interface', () {
+    final registry = Registry();
+    registry.add(Counter('a', 5));
+    registry.add(Counter('b', 5)..increment());
+
+    final List<CounterInterface> all = registry.all();
+    expect(all.map(describe), ['a=0', 'b=1']);
+    expect(registry.byName().map((name, c) => MapEntry(name, c.value())), {'a': 0, 'b': 1});
+    expect(registry.get_('b')?.value(), 1);
+    expect(registry.get_('c'), isNull);
+    expect(total([Counter('c', 1)..increment(), registry.get_('b') as Counter]), 2);
+  });
+}
@@ -0,0 +1,6 @@
+use anyhow::Result;
+
+#[test]
+fn object_interfaces() -> Result<()> {
+    uniffi_dart::testing::run_test("object_interfaces", "src/api.udl", Some("uniffi.toml"))
+}
@@ -0,0 +1,106 @@
+// A tree-shaped enum
+#[derive(Debug, Clone, uniffi::Enum)]
+pub enum Node {
+    Leaf { value: i32 },
+    Branch { children: Vec<Node> },
+}
+
+// A record holding optional sequences of itself
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct Category {
+    name: String,
+    subcategories: Option<Vec<Category>>,
+}
+
+// A record and an enum referring to each other
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct Folder {
+    name: String,
+    entries: Vec<Entry>,
+}
+
+#[derive(Debug, Clone, uniffi::Enum)]
+pub enum Entry {
+    File { name: String, size: u64 },
+    Directory { folder: Folder },
+    Alias { targets: Vec<Entry> },
+}
+
+// Two records referring to each other
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct Employee {
+    name: String,
+    team: Option<Team>,
+}
+
+#[derive(Debug, Clone, uniffi::Record)]
+pub struct Team {
+    name: String,
+    members: Vec<Employee>,
+}
+
+#[uniffi::export]
+pub fn sample_tree() -> Node {
+    Node::Branch {
+        children: vec![
+            Node::Leaf { value: 1 },
+            Node::Branch {
+                children: vec![Node::Leaf { value: 2 }, Node::Leaf { value: 3 }],
+            },
+            Node::Branch { children: vec![] },
+        ],
+    }
+}
+
+#[uniffi::export]
+pub fn sum_tree(node: Node) -> i32 {
+    match node {
+        Node::Leaf { value } => value,
+        Node::Branch { children } => children.into_iter().map(sum_tree).sum(),
+    }
+}
+
+#[uniffi::export]
+pub fn echo_tree(node: Node) -> Node {
+    node
+}
+
+#[uniffi::export]
+pub fn echo_category(category: Category) -> Category {
+    category
+}
+
+#[uniffi::export]
+pub fn count_categories(category: Category) -> u32 {
+    1 + category
+        .subcategories
+        .unwrap_or_default()
+        .into_iter()
+        .map(count_categories)
+        .sum::<u32>()
+}
+
+#[uniffi::export]
+pub fn echo_folder(folder: Folder) -> Folder {
+    folder
+}
+
+#[uniffi::export]
+pub fn folder_size(folder: Folder) -> u64 {
+    folder
+        .entries
+        .into_iter()
+        .map(|entry| match entry {
+            Entry::File { size, .. } => size,
+            Entry::Directory { folder } => folder_size(folder),
+            Entry::Alias { .. } => 0,
+        })
+        .sum()
+}
+
+#[uniffi::export]
+pub fn echo_team(team: Team) -> Team {
+    team
+}
+
+uniffi::include_scaffolding!("api");
@@ -0,0 +1,106 @@
+import 'package:test/test.dart';
+import '../recursive_types.dart';
+
+// `Node` is sealed, so this switch is checked for exhaustiveness
+int sumNode(Node node) => switch (node) {
+      LeafNode(:final value) => value,
+      BranchNode(:final children) => children.fold(0, (sum, child) => sum + sumNode(child)),
+    };
+
+void main() {
+  test('recursive enums', () {
+    final tree = sampleTree();
+    expect(sumNode(tree), 6);
+    expect(sumTree(tree), 6);
+
+    final children = (tree as BranchNode).children;
+    expect(children.length, 3);
+    expect((children[0] as LeafNode).value, 1);
+    expect((children[2] as BranchNode).children, isEmpty);
+  });
+
+  test('recursive enums round-trip', () {
+    final tree = BranchNode([
+      LeafNode(10),
+      BranchNode([BranchNode([LeafNode(20)]), LeafNode(30)]),
+    ]);
+    expect(sumTree(tree), 60);
+    expect(sumNode(echoTree(tree)), 60);
+    expect(sumNode(echoTree(LeafNode(-1))), -1);
+  });
+
+  test('records holding optional sequences of themselves', () {
+    final category = Category("root", [
+      Category("books", [Category("fiction", null), Category("poetry", [])]),
+      Category("music", null),
+    ]);
+    expect(countCategories(category), 5);
+
+    final echoed = echoCategory(category);
+    expect(echoed.name, "root");
+    expect(echoed.subcategories!.length, 2);
+    expect(echoed.subcategories![0].subcategories![0].name, "fiction");
+    expect(echoed.subcategories![0].subcategories![0].subcategories, isNull);
+    expect(echoed.subcategories![0].subcategories![1].subcategories, isEmpty);
+    expect(echoed.subcategories![1].subcategories, isNull);
+  });
+
+  test('mutually recursive record and enum', () {
+    final folder = Folder("root", [
+      FileEntry(name: "a.txt", size: 10),
+      DirectoryEntry(Folder("nested", [
+        FileEntry(name: "b.txt", size: 20),
+        DirectoryEntry(Folder("empty", [])),
+      ])),
+      AliasEntry([FileEntry(name: "c.txt", size: 40)]),
+    ]);
+    expect(folderSize(folder), 30);
+
+    final echoed = echoFolder(folder);
+    expect(echoed.entries.length, 3);
+    final nested = (echoed.entries[1] as DirectoryEntry).folder;
+    expect(nested.name, "nested");
+    expect((nested.entries[0] as FileEntry).size, 20);
+    expect((nested.entries[1] as DirectoryEntry).folder.entries, isEmpty);
+    final alias = echoed.entries[2] as AliasEntry;
+    expect((alias.targets[0] as FileEntry).name, "c.txt");
+  });
+
+  test('mutually recursive records', () {
+    final team = Team("core", [
+      Employee("ada", null),
+      Employee("grace", Team("compilers", [Employee("frances", null)])),
+    ]);
+    final echoed = echoTeam(team);
+    expect(echoed.name, "core");
+    expect(echoed.members[0].team, isNull);
+    expect(echoed.members[1].team!.name, "compilers");
+    expect(echoed.members[1].team!.members[0].name, "frances");
+  });
+
+  test('value equality', () {
+    final folder = Folder("root", [
+      FileEntry(name: "a.txt", size: 10),
+      DirectoryEntry(Folder("nested", [FileEntry(name: "b.txt", size: 20)])),
+    ]);
+    final echoed = echoFolder(folder);
+    expect(identical(echoed, folder), isFalse);
+    expect(echoed, folder);
+    expect(echoed.hashCode, folder.hashCode);
+    expect(echoed == Folder("root", []), isFalse);
+    expect(FileEntry(name: "a.txt", size: 10) == FileEntry(name: "a.txt", size: 11), isFalse);
+
+    final team = Team("core", [Employee("ada", null)]);
+    expect(echoTeam(team), team);
+    expect({team, echoTeam(team)}.length, 1);
+    expect({echoTeam(team): 1}[team], 1);
+
+    expect(echoTree(sampleTree()), sampleTree());
+  });
+
+  test('readable toString', () {
+    expect(Employee("ada", null).toString(), "Employee(name: ada, team: null)");
+    expect(FileEntry(name: "a.txt", size: 10).toString(), "FileEntry(name: a.txt, size: 10)");
+    expect(AliasEntry([]).toString(), "AliasEntry(targets: [])");
+  });
+}
@@ -0,0 +1,6 @@
+use anyhow::Result;
+
+#[test]
+fn recursive_types() -> Result<()> {
+    uniffi_dart::testing::run_test("recursive_types", "src/api.udl", None)
+}
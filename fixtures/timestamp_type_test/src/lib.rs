@@ -0,0 +1,71 @@
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+#[derive(uniffi::Record)]
+pub struct AuditEntry {
+    pub action: String,
+    pub at: SystemTime,
+    pub expires_at: Option<SystemTime>,
+}
+
+fn from_epoch(seconds: i64, nanos: u32) -> SystemTime {
+    let offset = Duration::new(seconds.unsigned_abs(), nanos);
+    if seconds >= 0 {
+        UNIX_EPOCH + offset
+    } else {
+        UNIX_EPOCH - offset
+    }
+}
+
+#[uniffi::export]
+pub fn make_timestamp(seconds: i64, nanos: u32) -> SystemTime {
+    from_epoch(seconds, nanos)
+}
+
+#[uniffi::export]
+pub fn get_seconds(timestamp: SystemTime) -> i64 {
+    match timestamp.duration_since(UNIX_EPOCH) {
+        Ok(offset) => offset.as_secs() as i64,
+        Err(e) => -(e.duration().as_secs() as i64),
+    }
+}
+
+#[uniffi::export]
+pub fn get_nanos(timestamp: SystemTime) -> u32 {
+    match timestamp.duration_since(UNIX_EPOCH) {
+        Ok(offset) => offset.subsec_nanos(),
+        Err(e) => e.duration().subsec_nanos(),
+    }
+}
+
+#[uniffi::export]
+pub fn add_duration(timestamp: SystemTime, duration: Duration) -> SystemTime {
+    timestamp + duration
+}
+
+#[uniffi::export]
+pub fn get_audit_log() -> Vec<AuditEntry> {
+    vec![
+        AuditEntry {
+            action: "login".to_string(),
+            at: from_epoch(1_700_000_000, 123_456_000),
+            expires_at: Some(from_epoch(1_700_003_600, 0)),
+        },
+        AuditEntry {
+            action: "moon landing".to_string(),
+            at: from_epoch(-14_182_940, 0),
+            expires_at: None,
+        },
+    ]
+}
+
+#[uniffi::export]
+pub fn latest(timestamps: Vec<SystemTime>) -> Option<SystemTime> {
+    timestamps.into_iter().max()
+}
+
+#[uniffi::export]
+pub fn echo_audit_entry(entry: AuditEntry) -> AuditEntry {
+    entry
+}
+
+uniffi::include_scaffolding!("api");
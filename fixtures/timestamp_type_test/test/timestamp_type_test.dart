@@ -0,0 +1,75 @@
+import 'package:test/test.dart';
+import '../timestamp_type_test.dart';
+
+void main() {
+  test('rust return value seconds check', () {
+    final timestamp = makeTimestamp(5, 0);
+
+    expect(timestamp.isUtc, true);
+    expect(timestamp.millisecondsSinceEpoch, 5000);
+    expect(getSeconds(timestamp), 5);
+    expect(getNanos(timestamp), 0);
+  });
+
+  test('seconds data check from dart', () {
+    final timestamp = DateTime.utc(2024, 2, 29, 12, 30, 15);
+    expect(getSeconds(timestamp), timestamp.millisecondsSinceEpoch ~/ 1000);
+    expect(getNanos(timestamp), 0);
+    expect(makeTimestamp(getSeconds(timestamp), 0), timestamp);
+  });
+
+  test('check nanos/micros', () {
+    final timestamp = makeTimestamp(0, 3000);
+    expect(timestamp.microsecondsSinceEpoch, 3);
+    expect(getSeconds(timestamp), 0);
+    expect(getNanos(timestamp), 3000);
+  });
+
+  test('sub-microsecond precision is truncated', () {
+    expect(makeTimestamp(1, 999).microsecondsSinceEpoch, 1000000);
+    expect(makeTimestamp(-1, 999).microsecondsSinceEpoch, -1000000);
+  });
+
+  test('dates before 1970', () {
+    final landing = makeTimestamp(-14182940, 0);
+    expect(landing, DateTime.utc(1969, 7, 20, 20, 17, 40));
+    expect(getSeconds(landing), -14182940);
+
+    final beforeEpoch = DateTime.fromMicrosecondsSinceEpoch(-1500000, isUtc: true);
+    expect(getSeconds(beforeEpoch), -1);
+    expect(getNanos(beforeEpoch), 500000000);
+    expect(makeTimestamp(-1, 500000000), beforeEpoch);
+  });
+
+  test('local times keep their instant', () {
+    final local = DateTime(2001, 9, 9, 1, 46, 40);
+    expect(getSeconds(local), local.millisecondsSinceEpoch ~/ 1000);
+  });
+
+  test('timestamps mixed with durations', () {
+    final start = makeTimestamp(100, 0);
+    expect(addDuration(start, Duration(seconds: 20, microseconds: 5)),
+        makeTimestamp(120, 5000));
+  });
+
+  test('timestamps in records', () {
+    final log = getAuditLog();
+    expect(log.length, 2);
+    expect(log[0].action, "login");
+    expect(log[0].at.microsecondsSinceEpoch, 1700000000123456);
+    expect(log[0].expiresAt, makeTimestamp(1700003600, 0));
+    expect(log[1].at.year, 1969);
+    expect(log[1].expiresAt, null);
+
+    final echoed = echoAuditEntry(log[0]);
+    expect(echoed.at, log[0].at);
+    expect(echoed.expiresAt, log[0].expiresAt);
+  });
+
+  test('timestamps in sequences and optionals', () {
+    expect(latest([]), null);
+    expect(
+        latest([makeTimestamp(10, 0), makeTimestamp(-10, 0), makeTimestamp(30, 1000)]),
+        makeTimestamp(30, 1000));
+  });
+}
@@ -0,0 +1,6 @@
+use anyhow::Result;
+
+#[test]
+fn timestamp_type_test() -> Result<()> {
+    uniffi_dart::testing::run_test("timestamp_type_test", "src/api.udl", None)
+}
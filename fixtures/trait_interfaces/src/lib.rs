@@ -0,0 +1,116 @@
+use std::sync::{Arc, Mutex};
+
+// A trait that can only be implemented in Rust.
+#[uniffi::export]
+pub trait Shape: Send + Sync {
+    fn name(&self) -> String;
+    fn area(&self) -> f64;
+}
+
+struct Square {
+    side: f64,
+}
+
+impl Shape for Square {
+    fn name(&self) -> String {
+        "square".to_string()
+    }
+
+    fn area(&self) -> f64 {
+        self.side * self.side
+    }
+}
+
+struct Rectangle {
+    width: f64,
+    height: f64,
+}
+
+impl Shape for Rectangle {
+    fn name(&self) -> String {
+        "rectangle".to_string()
+    }
+
+    fn area(&self) -> f64 {
+        self.width * self.height
+    }
+}
+
+#[uniffi::export]
+pub fn make_square(side: f64) -> Arc<dyn Shape> {
+    Arc::new(Square { side })
+}
+
+#[uniffi::export]
+pub fn make_shapes() -> Vec<Arc<dyn Shape>> {
+    vec![
+        Arc::new(Square { side: 2.0 }),
+        Arc::new(Rectangle {
+            width: 2.0,
+            height: 3.0,
+        }),
+    ]
+}
+
+#[uniffi::export]
+pub fn describe(shape: Arc<dyn Shape>) -> String {
+    format!("{} with area {}", shape.name(), shape.area())
+}
+
+// A trait that can be implemented in either Rust or Dart.
+#[uniffi::export(with_foreign)]
+pub trait Greeter: Send + Sync {
+    fn greet(&self, name: String) -> String;
+}
+
+struct RustGreeter;
+
+impl Greeter for RustGreeter {
+    fn greet(&self, name: String) -> String {
+        format!("Hello from Rust, {name}!")
+    }
+}
+
+#[uniffi::export]
+pub fn rust_greeter() -> Arc<dyn Greeter> {
+    Arc::new(RustGreeter)
+}
+
+#[uniffi::export]
+pub fn greet_with(greeter: Arc<dyn Greeter>, name: String) -> String {
+    greeter.greet(name)
+}
+
+#[uniffi::export]
+pub fn greet_all(greeters: Vec<Arc<dyn Greeter>>, name: String) -> Vec<String> {
+    greeters.iter().map(|g| g.greet(name.clone())).collect()
+}
+
+#[derive(uniffi::Object)]
+pub struct GreeterHolder {
+    greeter: Mutex<Arc<dyn Greeter>>,
+}
+
+#[uniffi::export]
+impl GreeterHolder {
+    #[uniffi::constructor]
+    pub fn new(greeter: Arc<dyn Greeter>) -> Self {
+        Self {
+            greeter: Mutex::new(greeter),
+        }
+    }
+
+    pub fn get(&self) -> Arc<dyn Greeter> {
+        self.greeter.lock().unwrap().clone()
+    }
+
+    pub fn set(&self, greeter: Arc<dyn Greeter>) {
+        *self.greeter.lock().unwrap() = greeter;
+    }
+
+    pub fn greet(&self, name: String) -> String {
+        self.greeter.lock().unwrap().greet(name)
+    }
+}
+
+uniffi::include_scaffolding!("api");
@@ -0,0 +1,63 @@
+import 'package:test/test.dart';
+import '../trait_interfaces.dart';
+
+class DartGreeter implements Greeter {
+  final String prefix;
+
+  DartGreeter(this.prefix);
+
+  @override
+  String greet(String name) {
+    return "$prefix, $name!";
+  }
+}
+
+class DartShape implements Shape {
+  @override
+  String name() => "dart";
+
+  @override
+  double area() => 0;
+}
+
+void main() {
+  test('Rust-only traits', () {
+    final square = makeSquare(3);
+    expect(square.name(), "square");
+    expect(square.area(), 9);
+    expect(describe(square), "square with area 9");
+
+    final shapes = makeShapes();
+    expect(shapes.map((s) => s.name()).toList(), ["square", "rectangle"]);
+    expect(shapes.map((s) => s.area()).toList(), [4, 6]);
+  });
+
+  test('Rust-only traits cannot be implemented in Dart', () {
+    expect(() => describe(DartShape()), throwsUnsupportedError);
+  });
+
+  test('Rust implementation of a foreign trait', () {
+    final greeter = rustGreeter();
+    expect(greeter.greet("Dart"), "Hello from Rust, Dart!");
+    expect(greetWith(greeter, "Dart"), "Hello from Rust, Dart!");
+  });
+
+  test('Dart implementation of a foreign trait', () {
+    expect(greetWith(DartGreeter("Hi"), "Rust"), "Hi, Rust!");
+    expect(
+      greetAll([DartGreeter("Hi"), rustGreeter(), DartGreeter("Hey")], "you"),
+      ["Hi, you!", "Hello from Rust, you!", "Hey, you!"],
+    );
+  });
+
+  test('Dart implementation round-trips through Rust', () {
+    final dartGreeter = DartGreeter("Hello");
+    final holder = GreeterHolder(dartGreeter);
+    expect(holder.greet("holder"), "Hello, holder!");
+    expect(holder.get_().greet("again"), "Hello, again!");
+
+    holder.set_(rustGreeter());
+    expect(holder.greet("holder"), "Hello from Rust, holder!");
+    expect(holder.get_().greet("again"), "Hello from Rust, again!");
+  });
+}
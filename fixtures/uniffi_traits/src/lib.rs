@@ -0,0 +1,52 @@
+use std::fmt;
+
+#[derive(Debug, PartialEq, Eq, Hash, uniffi::Object)]
+#[uniffi::export(Debug, Display, Eq, Hash)]
+pub struct Color {
+    name: String,
+}
+
+#[uniffi::export]
+impl Color {
+    #[uniffi::constructor]
+    fn new(name: String) -> Self {
+        Self { name }
+    }
+}
+
+impl fmt::Display for Color {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "color {}", self.name)
+    }
+}
+
+#[derive(Debug, uniffi::Object)]
+#[uniffi::export(Debug)]
+pub struct Token {
+    id: u32,
+}
+
+#[uniffi::export]
+impl Token {
+    #[uniffi::constructor]
+    fn new(id: u32) -> Self {
+        Self { id }
+    }
+
+    fn id(&self) -> u32 {
+        self.id
+    }
+}
+
+#[derive(uniffi::Object)]
+pub struct Plain;
+
+#[uniffi::export]
+impl Plain {
+    #[uniffi::constructor]
+    fn new() -> Self {
+        Self
+    }
+}
+
+uniffi::include_scaffolding!("api");
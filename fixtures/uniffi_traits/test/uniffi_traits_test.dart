@@ -0,0 +1,29 @@
+import 'package:test/test.dart';
+import '../uniffi_traits.dart';
+
+void main() {
+  test('toString uses Display over Debug', () {
+    expect(Color('red').toString(), 'color red');
+  });
+
+  test('toString falls back to Debug', () {
+    final token = Token(7);
+    expect(token.id(), 7);
+    expect(token.toString(), 'Token { id: 7 }');
+  });
+
+  test('operator == and hashCode use Eq and Hash', () {
+    final red = Color('red');
+    expect(red, Color('red'));
+    expect(red, isNot(Color('blue')));
+    expect(red.hashCode, Color('red').hashCode);
+    expect({red, Color('red'), Color('blue')}, hasLength(2));
+  });
+
+  test('objects without traits keep identity semantics', () {
+    final plain = Plain();
+    expect(plain, plain);
+    expect(plain, isNot(Plain()));
+    expect(Token(1), isNot(Token(1)));
+  });
+}
@@ -0,0 +1,6 @@
+use anyhow::Result;
+
+#[test]
+fn uniffi_traits() -> Result<()> {
+    uniffi_dart::testing::run_test("uniffi_traits", "src/api.udl", None)
+}
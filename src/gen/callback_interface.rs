@@ -41,12 +41,12 @@ impl Renderable for CallbackInterfaceCodeType {
         let callback = type_helper.get_ci().get_callback_interface_definition(&self.name).unwrap();
         
         // Generate all necessary components for the callback interface
-        let interface = generate_callback_interface(callback.name(), &callback.as_codetype().ffi_converter_name(), &callback.methods(), type_helper);
+        let interface = generate_callback_interface(callback.name(), callback.docstring(), &callback.as_codetype().ffi_converter_name(), &callback.methods(), type_helper);
         let vtable_interface = generate_callback_vtable_interface(callback.name(), &callback.methods());
-        let functions = generate_callback_functions(callback.name(), &callback.methods(), type_helper);
+        let functions = generate_callback_functions(callback.name(), &self.ffi_converter_name(), &callback.methods(), type_helper);
         let namespace = type_helper.get_ci().namespace_for_type(&callback.as_type())
             .unwrap_or_else(|_| type_helper.get_ci().namespace());
-        let vtable_init = generate_callback_interface_vtable_init_function(callback.name(), &callback.methods(), namespace);
+        let vtable_init = generate_callback_interface_vtable_init_function(callback.name(), &self.ffi_converter_name(), &callback.methods(), namespace);
         
         quote! {
             $interface
@@ -59,6 +59,7 @@ impl Renderable for CallbackInterfaceCodeType {
 
 pub fn generate_callback_interface(
     callback_name: &str,
+    docstring: Option<&str>,
     ffi_converter_name: &str,
     methods: &[&Method],
     type_helper: &dyn TypeHelperRenderer,
@@ -69,6 +70,7 @@ pub fn generate_callback_interface(
 
     let tokens = quote! {
         // This is the abstract class to be implemented
+        $(DartCodeOracle::doc_comment(docstring))
         abstract class $cls_name {
             $(for m in methods {
                 $(generate_callback_methods_definitions(m, type_helper))
@@ -119,28 +121,28 @@ pub fn generate_callback_interface(
     tokens
 }
 
-fn generate_callback_methods_definitions(method: &Method, type_helper: &dyn TypeHelperRenderer) -> dart::Tokens {
-    let method_name = DartCodeOracle::fn_name(&method.name());
-    let dart_args = &method.arguments().iter().map(|arg| {
-        let arg_type = arg.as_renderable().render_type(&arg.as_type(), type_helper);
-        let arg_name = DartCodeOracle::var_name(arg.name());
-
-
-        quote!($arg_type $arg_name)
-    }).into_iter().collect::<Vec<_>>();
+pub fn generate_callback_methods_definitions(method: &Method, type_helper: &dyn TypeHelperRenderer) -> dart::Tokens {
+    let method_name = DartCodeOracle::fn_name(method.name());
+    let dart_args = DartCodeOracle::arguments(&method.arguments(), type_helper);
 
     let ret_type = if let Some(ret) = method.return_type() {
         ret.as_renderable().render_type(ret, type_helper)
     } else {
         quote!(void)
     };
+    let ret_type = if method.is_async() {
+        quote!(Future<$ret_type>)
+    } else {
+        ret_type
+    };
 
     quote!(
-        $ret_type $method_name($(for a in dart_args => $a,));
+        $(DartCodeOracle::doc_comment(method.docstring()))
+        $ret_type $method_name($dart_args);
     )
 }
 
-fn generate_callback_methods_signatures(callback_name: &str, methods: &[&Method], type_helper: &dyn TypeHelperRenderer) -> dart::Tokens {
+pub fn generate_callback_methods_signatures(callback_name: &str, methods: &[&Method], type_helper: &dyn TypeHelperRenderer) -> dart::Tokens {
     let mut tokens = dart::Tokens::new();
     for (method_index, method) in methods.iter().enumerate() {
         //let method_name = DartCodeOracle::fn_name(method.name());
@@ -183,7 +185,7 @@ fn generate_callback_methods_signatures(callback_name: &str, methods: &[&Method]
 
 pub fn generate_callback_vtable_interface(callback_name: &str, methods: &[&Method]) -> dart::Tokens {
     let vtable_name = format!("UniffiVTableCallbackInterface{}", callback_name);
-    let methods_vec: Vec<_> = methods.into_iter().enumerate().collect();
+    let methods_vec: Vec<_> = methods.iter().enumerate().collect();
 
     quote! {
         final class $vtable_name extends Struct {
@@ -195,11 +197,10 @@ pub fn generate_callback_vtable_interface(callback_name: &str, methods: &[&Metho
     }
 }
 
-pub fn generate_callback_functions(callback_name: &str, methods: &[&Method], type_helper: &dyn TypeHelperRenderer) -> dart::Tokens {    
-    let cls_name = &DartCodeOracle::class_name(callback_name);
+pub fn generate_callback_functions(callback_name: &str, ffi_converter_name: &str, methods: &[&Method], type_helper: &dyn TypeHelperRenderer) -> dart::Tokens {
 
     let functions: Vec<dart::Tokens> = methods.iter().enumerate().map(|(index, m)| {
-        let method_name = &format!("{}", &DartCodeOracle::fn_name(m.name()));
+        let method_name = &DartCodeOracle::fn_name(m.name());
         let ffi_method_type = &format!("UniffiCallbackInterface{}Method{}", callback_name, index);
         let _dart_method_type = &format!("UniffiCallbackInterface{}Method{}Dart", callback_name, index);
 
@@ -229,7 +230,7 @@ pub fn generate_callback_functions(callback_name: &str, methods: &[&Method], typ
         };
 
         // Get the appropriate out return type
-        let out_return_type = DartCodeOracle::callback_out_return_type(m.return_type());
+        let out_return_type = DartCodeOracle::callback_out_return_type(m.return_type(), type_helper.get_ci());
 
         // Generate the function body
         let callback_method_name = &format!("{}{}", &DartCodeOracle::fn_name(callback_name), &DartCodeOracle::class_name(m.name()));
@@ -238,7 +239,7 @@ pub fn generate_callback_functions(callback_name: &str, methods: &[&Method], typ
             void $callback_method_name(int uniffiHandle, $(for param in &param_types => $param,) $out_return_type outReturn, Pointer<RustCallStatus> callStatus) {
                 final status = callStatus.ref;
                 try {
-                    final obj = FfiConverterCallbackInterface$cls_name._handleMap.get(uniffiHandle);
+                    final obj = $ffi_converter_name._handleMap.get(uniffiHandle);
                     $(arg_lifts)
                     $call_dart_method
                 } catch (e) {
@@ -262,7 +263,7 @@ pub fn generate_callback_functions(callback_name: &str, methods: &[&Method], typ
 
         void $free_callback_fn(int handle) {
             try {
-                FfiConverterCallbackInterface$cls_name._handleMap.remove(handle);
+                $ffi_converter_name._handleMap.remove(handle);
             } catch (e) {
                 // Optionally log error, but do not return anything.
             }
@@ -273,7 +274,7 @@ pub fn generate_callback_functions(callback_name: &str, methods: &[&Method], typ
     }
 }
 
-pub fn generate_callback_interface_vtable_init_function(callback_name: &str, methods: &[&Method], namespace: &str) -> dart::Tokens {
+pub fn generate_callback_interface_vtable_init_function(callback_name: &str, ffi_converter_name: &str, methods: &[&Method], namespace: &str) -> dart::Tokens {
     let vtable_name = &format!("UniffiVTableCallbackInterface{}", callback_name);
     let vtable_static_instance_name = format!("{}{}", DartCodeOracle::fn_name(callback_name), "VTable");
     let init_fn_name = &format!("init{}VTable", callback_name);
@@ -283,7 +284,7 @@ pub fn generate_callback_interface_vtable_init_function(callback_name: &str, met
 
         void $init_fn_name() {
             // Make initialization idempotent - return early if already initialized
-            if (FfiConverterCallbackInterface$(DartCodeOracle::class_name(callback_name))._vtableInitialized) {
+            if ($ffi_converter_name._vtableInitialized) {
                 return;
             }
 
@@ -301,7 +302,7 @@ pub fn generate_callback_interface_vtable_init_function(callback_name: &str, met
             });
 
             // Update the flag to prevent re-initialization
-            FfiConverterCallbackInterface$(DartCodeOracle::class_name(callback_name))._vtableInitialized = true;
+            $ffi_converter_name._vtableInitialized = true;
         }
     }
 }
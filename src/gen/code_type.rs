@@ -1,5 +1,8 @@
 use std::fmt::Debug;
 use uniffi_bindgen::backend::Literal;
+use uniffi_bindgen::ComponentInterface;
+
+use super::Config;
 
 /// A trait tor the implementation.
 pub trait CodeType: Debug {
@@ -7,6 +10,12 @@ pub trait CodeType: Debug {
     /// method signatures and property declarations.
     fn type_label(&self) -> String;
 
+    /// The type label under the given configuration, which differs from [`Self::type_label`]
+    /// for types whose Dart representation is configurable, e.g. `u64` with `u64_as_bigint`.
+    fn configured_type_label(&self, _config: &Config) -> String {
+        self.type_label()
+    }
+
     /// A representation of this type label that can be used as part of another
     /// identifier. e.g. `read_foo()`, or `FooInternals`.
     ///
@@ -16,7 +25,8 @@ pub trait CodeType: Debug {
         self.type_label()
     }
 
-    fn literal(&self, _literal: &Literal) -> String {
+    /// A Dart constant expression for `literal`, usable as the default value of a parameter.
+    fn literal(&self, _literal: &Literal, _ci: &ComponentInterface) -> String {
         unimplemented!("Unimplemented for {}", self.type_label())
     }
 
@@ -2,9 +2,12 @@ use genco::lang::dart;
 use genco::prelude::*;
 use paste::paste;
 use crate::gen::CodeType;
+use uniffi_bindgen::backend::Literal;
 use uniffi_bindgen::interface::Type;
+use uniffi_bindgen::ComponentInterface;
 
 use super::oracle::{AsCodeType, DartCodeOracle};
+use super::Config;
 use crate::gen::render::{Renderable, TypeHelperRenderer};
 
 macro_rules! impl_code_type_for_compound {
@@ -27,12 +30,25 @@ macro_rules! impl_code_type_for_compound {
 
             impl CodeType for $T  {
                 fn type_label(&self) -> String {
-                    format!($type_label_pattern, DartCodeOracle::find(self.inner()).type_label())
+                    self.configured_type_label(&Config::default())
+                }
+
+                fn configured_type_label(&self, config: &Config) -> String {
+                    format!($type_label_pattern, DartCodeOracle::find(self.inner()).configured_type_label(config))
                 }
 
                 fn canonical_name(&self) -> String {
                     format!($canonical_name_pattern, DartCodeOracle::find(self.inner()).canonical_name())
                 }
+
+                fn literal(&self, literal: &Literal, ci: &ComponentInterface) -> String {
+                    match literal {
+                        Literal::None => "null".into(),
+                        Literal::Some { inner } => DartCodeOracle::find(self.inner()).literal(inner, ci),
+                        Literal::EmptySequence => "const []".into(),
+                        _ => unreachable!("Unexpected literal for {}: {:?}", self.type_label(), literal),
+                    }
+                }
             }
         }
     }
@@ -43,9 +59,9 @@ macro_rules! impl_renderable_for_compound {
        paste! {
             impl Renderable for $T {
                 fn render_type_helper(&self, type_helper: &dyn TypeHelperRenderer) -> dart::Tokens {
-                    type_helper.include_once_check(&self.ffi_converter_name(), &self.self_type);
+                    type_helper.include_once_check(&self.canonical_name(), &self.self_type);
                     let inner_codetype = DartCodeOracle::find(self.inner());
-                    let inner_type_label = inner_codetype.type_label();
+                    let inner_type_label = DartCodeOracle::type_label(self.inner(), type_helper.get_config());
 
                     type_helper.include_once_check(&inner_codetype.canonical_name(), &self.inner()); // Add the Inner FFI Converter
 
@@ -120,9 +136,9 @@ macro_rules! impl_renderable_for_compound {
             impl Renderable for SequenceCodeType {
                 fn render_type_helper(&self, type_helper: &dyn TypeHelperRenderer) -> dart::Tokens {
 
-                    type_helper.include_once_check(&self.ffi_converter_name(), &self.self_type);
+                    type_helper.include_once_check(&self.canonical_name(), &self.self_type);
                     let inner_codetype = self.inner().as_codetype();
-                    let inner_type_label = inner_codetype.type_label();
+                    let inner_type_label = DartCodeOracle::type_label(self.inner(), type_helper.get_config());
 
                     type_helper.include_once_check(&inner_codetype.canonical_name(), &self.inner()); // Add the Inner FFI Converter
 
@@ -178,6 +194,125 @@ macro_rules! impl_renderable_for_compound {
    }
 }
 
+#[derive(Debug)]
+pub struct MapCodeType {
+    self_type: Type,
+    key: Type,
+    value: Type,
+}
+
+impl MapCodeType {
+    pub fn new(self_type: Type, key: Type, value: Type) -> Self {
+        Self {
+            self_type,
+            key,
+            value,
+        }
+    }
+
+    fn key(&self) -> &Type {
+        &self.key
+    }
+
+    fn value(&self) -> &Type {
+        &self.value
+    }
+}
+
+impl CodeType for MapCodeType {
+    fn type_label(&self) -> String {
+        self.configured_type_label(&Config::default())
+    }
+
+    fn configured_type_label(&self, config: &Config) -> String {
+        format!(
+            "Map<{}, {}>",
+            DartCodeOracle::find(self.key()).configured_type_label(config),
+            DartCodeOracle::find(self.value()).configured_type_label(config),
+        )
+    }
+
+    fn canonical_name(&self) -> String {
+        format!(
+            "Map{}{}",
+            DartCodeOracle::find(self.key()).canonical_name(),
+            DartCodeOracle::find(self.value()).canonical_name(),
+        )
+    }
+
+    fn literal(&self, literal: &Literal, _ci: &ComponentInterface) -> String {
+        match literal {
+            Literal::EmptyMap => "const {}".into(),
+            _ => unreachable!("Unexpected literal for {}: {:?}", self.type_label(), literal),
+        }
+    }
+}
+
+impl Renderable for MapCodeType {
+    fn render_type_helper(&self, type_helper: &dyn TypeHelperRenderer) -> dart::Tokens {
+        type_helper.include_once_check(&self.canonical_name(), &self.self_type);
+        let key_codetype = self.key().as_codetype();
+        let value_codetype = self.value().as_codetype();
+
+        // Add the key and value FFI Converters
+        type_helper.include_once_check(&key_codetype.canonical_name(), self.key());
+        type_helper.include_once_check(&value_codetype.canonical_name(), self.value());
+
+        let cl_name = &self.ffi_converter_name();
+        let key_type_label = &DartCodeOracle::type_label(self.key(), type_helper.get_config());
+        let value_type_label = &DartCodeOracle::type_label(self.value(), type_helper.get_config());
+        let type_label = &DartCodeOracle::type_label(&self.self_type, type_helper.get_config());
+
+        let key_cl_converter_name = &key_codetype.ffi_converter_name();
+        let value_cl_converter_name = &value_codetype.ffi_converter_name();
+
+        // Wire format: an i32 entry count followed by each key and value in turn
+        quote! {
+            class $cl_name {
+                static $type_label lift(RustBuffer buf) {
+                    return $cl_name.read(buf.asUint8List()).value;
+                }
+
+                static LiftRetVal<$type_label> read(Uint8List buf) {
+                    final map = <$key_type_label, $value_type_label>{};
+                    final length = buf.buffer.asByteData(buf.offsetInBytes).getInt32(0);
+                    int offset = buf.offsetInBytes + 4;
+                    for (var i = 0; i < length; i++) {
+                        final k = $key_cl_converter_name.read(Uint8List.view(buf.buffer, offset));
+                        offset += k.bytesRead;
+                        final v = $value_cl_converter_name.read(Uint8List.view(buf.buffer, offset));
+                        offset += v.bytesRead;
+                        map[k.value] = v.value;
+                    }
+                    return LiftRetVal(map, offset - buf.offsetInBytes);
+                }
+
+                static int write($type_label value, Uint8List buf) {
+                    buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, value.length);
+                    int offset = buf.offsetInBytes + 4;
+                    for (final key in value.keys) {
+                        offset += $key_cl_converter_name.write(key, Uint8List.view(buf.buffer, offset));
+                        offset += $value_cl_converter_name.write(value[key] as $value_type_label, Uint8List.view(buf.buffer, offset));
+                    }
+                    return offset - buf.offsetInBytes;
+                }
+
+                static int allocationSize($type_label value) {
+                    return value.keys
+                        .map((key) => $key_cl_converter_name.allocationSize(key) + $value_cl_converter_name.allocationSize(value[key] as $value_type_label))
+                        .fold(4, (a, b) => a + b);
+                }
+
+                static RustBuffer lower($type_label value) {
+                    final buf = Uint8List(allocationSize(value));
+                    write(value, buf);
+                    return toRustBuffer(buf);
+                }
+            }
+        }
+    }
+}
+
 impl_code_type_for_compound!(OptionalCodeType, "{}?", "Optional{}");
 impl_code_type_for_compound!(SequenceCodeType, "List<{}>", "Sequence{}");
 
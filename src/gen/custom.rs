@@ -41,11 +41,55 @@ impl Renderable for CustomCodeType {
         let ffi_converter_name = &self.ffi_converter_name();
         let type_name = &self.type_label();
         let builtin_ffi_converter_name = &(*self.builtin).as_codetype().ffi_converter_name();
-        let builtin_name = DartCodeOracle::dart_type_label(Some(&*self.builtin));
+        let builtin_name = DartCodeOracle::type_label(&self.builtin, type_helper.get_config());
+
+        let Some(config) = type_helper.get_config().custom_type(&self.name) else {
+            return quote! {
+                typedef $(type_name) = $(builtin_name);
+                typedef $(ffi_converter_name) = $(builtin_ffi_converter_name);
+            };
+        };
+
+        // The wrapping converter delegates the wire format to the builtin one
+        type_helper.include_once_check(&self.builtin.as_codetype().canonical_name(), &self.builtin);
+        let ffi_type = DartCodeOracle::native_dart_type_label(Some(&*self.builtin), type_helper.get_ci());
+        // Skip the alias when the configured class already carries the custom type's name
+        let type_alias = match config.type_name() {
+            _ if config.is_extension_type() => {
+                quote!(extension type const $(type_name)($(&builtin_name) value) {})
+            }
+            Some(concrete) if concrete == type_name => quote!(),
+            Some(concrete) => quote!(typedef $(type_name) = $(concrete);),
+            None => quote!(typedef $(type_name) = $(&builtin_name);),
+        };
+        let lift = |value: &str| config.lift(value);
+        let lower = |value: &str| config.lower(value);
 
         quote! {
-            typedef $(type_name) = $(builtin_name);
-            typedef $(ffi_converter_name) = $(builtin_ffi_converter_name);
+            $(type_alias)
+
+            class $(ffi_converter_name) {
+                static $(type_name) lift($(&ffi_type) value) {
+                    return $(lift(&format!("{builtin_ffi_converter_name}.lift(value)")));
+                }
+
+                static $(&ffi_type) lower($(type_name) value) {
+                    return $(builtin_ffi_converter_name).lower($(lower("value")));
+                }
+
+                static LiftRetVal<$(type_name)> read(Uint8List buf) {
+                    final builtin = $(builtin_ffi_converter_name).read(buf);
+                    return LiftRetVal($(lift("builtin.value")), builtin.bytesRead);
+                }
+
+                static int write($(type_name) value, Uint8List buf) {
+                    return $(builtin_ffi_converter_name).write($(lower("value")), buf);
+                }
+
+                static int allocationSize($(type_name) value) {
+                    return $(builtin_ffi_converter_name).allocationSize($(lower("value")));
+                }
+            }
         }
     }
 }
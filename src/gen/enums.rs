@@ -1,9 +1,12 @@
 use genco::prelude::*;
 use crate::gen::CodeType;
 use uniffi_bindgen::backend::Literal;
-use uniffi_bindgen::interface::{AsType, Enum, Field, Type};
+use uniffi_bindgen::interface::{AsType, Enum, Field};
+use uniffi_bindgen::ComponentInterface;
 use heck::ToLowerCamelCase;
 
+use super::json;
+use super::Config;
 use super::oracle::{AsCodeType, DartCodeOracle};
 use super::render::{AsRenderable, Renderable, TypeHelperRenderer};
 
@@ -27,15 +30,18 @@ impl CodeType for EnumCodeType {
         self.id.to_string()
     }
 
-    fn literal(&self, literal: &Literal) -> String {
-        if let Literal::Enum(v, _) = literal {
-            format!(
-                "{}{}",
-                self.type_label(),
-                DartCodeOracle::enum_variant_name(v)
-            )
+    fn literal(&self, literal: &Literal, ci: &ComponentInterface) -> String {
+        let Literal::Enum(v, _) = literal else {
+            unreachable!("Unexpected literal for {}: {:?}", self.type_label(), literal);
+        };
+        let is_plain_enum = ci
+            .get_enum_definition(&self.id)
+            .is_some_and(|e| e.is_flat() && !ci.is_name_used_as_error(e.name()));
+        if is_plain_enum {
+            format!("{}.{}", self.type_label(), DartCodeOracle::enum_variant_name(v))
         } else {
-            unreachable!();
+            // Fieldless variants of the class hierarchy have const constructors
+            format!("const {}{}()", DartCodeOracle::class_name(v), self.type_label())
         }
     }
 
@@ -45,44 +51,79 @@ impl CodeType for EnumCodeType {
 }
 
 impl Renderable for EnumCodeType {
-    fn render_type_helper(&self, type_helper: &dyn TypeHelperRenderer) -> dart::Tokens {
-        if type_helper.check(&self.id) {
-            quote!()
-        } else if let Some(enum_) = type_helper.get_enum(&self.id) {
-            generate_enum(enum_, type_helper)
-        } else {
-            unreachable!()
-        }
+    // Every enum is rendered exactly once from its definition (see `TypeHelpersRenderer`),
+    // so references to it, including recursive ones from its variants, render nothing here.
+    fn render_type_helper(&self, _type_helper: &dyn TypeHelperRenderer) -> dart::Tokens {
+        quote!()
     }
 }
 
+/// Whether a flat enum is rendered as a class keeping the raw index of its variant, see
+/// `generate_open_flat_enum`, rather than as a Dart enum.
+pub fn is_open_flat_enum(obj: &Enum, ci: &ComponentInterface, config: &Config) -> bool {
+    obj.is_flat() && !ci.is_name_used_as_error(obj.name()) && config.unknown_enum_variants() && obj.is_non_exhaustive()
+}
+
 pub fn generate_enum(obj: &Enum, type_helper: &dyn TypeHelperRenderer) -> dart::Tokens {
 
     let dart_cls_name = &DartCodeOracle::class_name(obj.name());
     let ffi_converter_name = &obj.as_codetype().ffi_converter_name();
-    if obj.is_flat() {
+    let is_error_enum = type_helper.get_ci().is_name_used_as_error(obj.name());
+    // Flat errors still carry their `Display` message on the wire, so they get the
+    // exception class hierarchy below rather than a plain Dart enum.
+    let is_flat_error = is_error_enum && obj.is_flat();
+    // Variants unknown to these bindings are lifted into an extra case rather than throwing
+    let unknown_variant = (type_helper.get_config().unknown_enum_variants() && obj.is_non_exhaustive())
+        .then(|| unknown_variant_name(obj));
+    let is_serializable = json::is_serializable(obj.name(), type_helper.get_ci(), type_helper.get_config());
+    if obj.is_flat() && !is_error_enum {
+        let json_members = if is_serializable {
+            json::flat_enum_members(obj, dart_cls_name, unknown_variant.as_deref())
+        } else {
+            quote!()
+        };
+        let unknown_read = match &unknown_variant {
+            Some(name) => quote!(return LiftRetVal($dart_cls_name.$(DartCodeOracle::enum_variant_name(name))(index), 4);),
+            None => quote!(throw UniffiInternalError(UniffiInternalError.unexpectedEnumCase, "Unable to determine enum variant");),
+        };
+        // Enums with an unknown case keep the raw index of every variant, see `generate_open_flat_enum`
+        let raw_index = match &unknown_variant {
+            Some(_) => quote!(value.rawIndex),
+            None => quote!(value.index + 1),
+        };
         quote! {
-            enum $dart_cls_name {
-                $(for variant in obj.variants() =>
-                $(DartCodeOracle::enum_variant_name(variant.name())),)
-                ;
-            }
+            $(generate_flat_enum(obj, dart_cls_name, unknown_variant.as_deref(), json_members))
 
             class $ffi_converter_name {
                 static $dart_cls_name lift( RustBuffer buffer) {
-                    final index = buffer.asUint8List().buffer.asByteData().getInt32(0);
+                    return $ffi_converter_name.read(buffer.asUint8List()).value;
+                }
+
+                static LiftRetVal<$dart_cls_name> read( Uint8List buf) {
+                    final index = buf.buffer.asByteData(buf.offsetInBytes).getInt32(0);
                     switch(index) {
                         $(for (index, variant) in obj.variants().iter().enumerate() =>
                         case $(index + 1):
-                            return $dart_cls_name.$(DartCodeOracle::enum_variant_name(variant.name()));
+                            return LiftRetVal($dart_cls_name.$(DartCodeOracle::enum_variant_name(variant.name())), 4);
                         )
                         default:
-                            throw UniffiInternalError(UniffiInternalError.unexpectedEnumCase, "Unable to determine enum variant");
+                            $unknown_read
                     }
                 }
 
-                static RustBuffer lower( $dart_cls_name input) {
-                    return toRustBuffer(createUint8ListFromInt(input.index + 1));
+                static RustBuffer lower( $dart_cls_name value) {
+                    final buf = Uint8List(allocationSize(value));
+                    write(value, buf);
+                    return toRustBuffer(buf);
+                }
+
+                static int allocationSize($dart_cls_name value) {
+                    return 4;
+                }
+
+                static int write( $dart_cls_name value, Uint8List buf) {
+                    buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, $raw_index);
+                    return 4;
                 }
             }
         }
@@ -99,20 +140,19 @@ pub fn generate_enum(obj: &Enum, type_helper: &dyn TypeHelperRenderer) -> dart::
         fn field_ffi_converter_name(field: &Field) -> String {
             field.as_type().as_codetype().ffi_converter_name().replace("Error", "Exception")
         }
-        fn is_flat_enum(field: &Field, type_helper: &dyn TypeHelperRenderer) -> bool {
-            if let Type::Enum { name, .. } = &field.as_type() {
-                if let Some(enum_def) = type_helper.get_enum(name) {
-                    return enum_def.is_flat();
-                }
-            }
-            false
-        }
-
         for (index, variant_obj) in obj.variants().iter().enumerate() {
             for f in variant_obj.fields() {
                 type_helper.include_once_check(&f.as_codetype().canonical_name(), &f.as_type());
             }
             let variant_dart_cls_name = &format!("{}{}", DartCodeOracle::class_name(variant_obj.name()), dart_cls_name);
+
+            if is_flat_error {
+                variants.push(quote! {
+                    $(DartCodeOracle::doc_comment(variant_obj.docstring()))
+                    $(generate_flat_error_variant(variant_dart_cls_name, dart_cls_name, index))
+                });
+                continue;
+            }
             
             // Prepare constructor parameters
             let constructor_params = variant_obj.fields().iter().enumerate().map(|(i, field)| {
@@ -133,81 +173,41 @@ pub fn generate_enum(obj: &Enum, type_helper: &dyn TypeHelperRenderer) -> dart::
             
             // Pre-process field reading code
             let field_read_code: Vec<dart::Tokens> = variant_obj.fields().iter().enumerate().map(|(i, field)| {
-                if is_flat_enum(field, type_helper) {
-                    // Handle flat enums specially - they serialize as int32 (4 bytes)
-                    quote!(
-                        final $(field_name(field, i))_int = buf.buffer.asByteData(new_offset).getInt32(0);
-                        final $(field_name(field, i)) = $(field_ffi_converter_name(field)).lift(toRustBuffer(createUint8ListFromInt($(field_name(field, i))_int)));
-                        new_offset += 4;
-                    )
-                } else {
-                    quote!(
-                        final $(field_name(field, i))_lifted = $(field_ffi_converter_name(field)).read(Uint8List.view(buf.buffer, new_offset));
-                        final $(field_name(field, i)) = $(field_name(field, i))_lifted.value;
-                        new_offset += $(field_name(field, i))_lifted.bytesRead;
-                    )
-                }
+                quote!(
+                    final $(field_name(field, i))_lifted = $(field_ffi_converter_name(field)).read(Uint8List.view(buf.buffer, new_offset));
+                    final $(field_name(field, i)) = $(field_name(field, i))_lifted.value;
+                    new_offset += $(field_name(field, i))_lifted.bytesRead;
+                )
             }).collect();
 
             // Pre-process allocation size calculation
             let allocation_parts: Vec<dart::Tokens> = variant_obj.fields().iter().enumerate().map(|(i, field)| {
-                if is_flat_enum(field, type_helper) {
-                    quote!(4 + ) // Flat enums are 4 bytes (int32)
-                } else {
-                    quote!($(field_ffi_converter_name(field)).allocationSize($(field_name(field, i))) + )
-                }
+                quote!($(field_ffi_converter_name(field)).allocationSize($(field_name(field, i))) + )
             }).collect();
 
             // Pre-process field write code
             let field_write_code: Vec<dart::Tokens> = variant_obj.fields().iter().enumerate().map(|(i, field)| {
-                if is_flat_enum(field, type_helper) {
-                    // Handle flat enums specially - lower to RustBuffer and extract int32
-                    quote!(
-                        final $(field_name(field, i))_buffer = $(field_ffi_converter_name(field)).lower($(field_name(field, i)));
-                        final $(field_name(field, i))_int = $(field_name(field, i))_buffer.asUint8List().buffer.asByteData().getInt32(0);
-                        buf.buffer.asByteData(new_offset).setInt32(0, $(field_name(field, i))_int);
-                        new_offset += 4;
-                    )
-                } else {
-                    quote!(
-                        new_offset += $(field_ffi_converter_name(field)).write($(field_name(field, i)), Uint8List.view(buf.buffer, new_offset));
-                    )
-                }
+                quote!(
+                    new_offset += $(field_ffi_converter_name(field)).write($(field_name(field, i)), Uint8List.view(buf.buffer, new_offset));
+                )
             }).collect();
 
-            // Generate simple toString() method for error enum variants
-            let to_string_method: dart::Tokens = if type_helper.get_ci().is_name_used_as_error(obj.name()) {
-                if variant_obj.has_fields() {
-                    let field_interpolations = variant_obj.fields().iter()
-                        .enumerate()
-                        .map(|(i, field)| format!("${}", field_name(field, i)))
-                        .collect::<Vec<_>>()
-                        .join(", ");
-                    let to_string_with_fields = format!("\"{}({})\"", variant_dart_cls_name, field_interpolations );
-                    quote!(
-                        @override
-                        String toString() {
-                            return $(&to_string_with_fields);
-                        }
-                    )
-                } else {
-                    quote!(
-                        @override
-                        String toString() {
-                            return $(format!("\"{}\"", variant_dart_cls_name));
-                        }
-                    )
-                }
-            } else {
-                quote!()
-            };
+            let value_members = DartCodeOracle::value_members(
+                variant_dart_cls_name,
+                &variant_obj.fields().iter().enumerate().map(|(i, field)| (field_name(field, i), field.as_type())).collect::<Vec<_>>(),
+                !variant_obj.has_nameless_fields(),
+            );
             
             variants.push(quote!{
-                class $variant_dart_cls_name extends $dart_cls_name {
-                    $(for (i, field) in variant_obj.fields().iter().enumerate() => final $(field_type(field, type_helper)) $(field_name(field, i));  )
+                $(DartCodeOracle::doc_comment(variant_obj.docstring()))
+                final class $variant_dart_cls_name extends $dart_cls_name {
+                    $(for (i, field) in variant_obj.fields().iter().enumerate() {
+                        $(DartCodeOracle::doc_comment(field.docstring()))
+                        final $(field_type(field, type_helper)) $(field_name(field, i));
+                    })
                     
-                    // Add the public const constructor
-                    $variant_dart_cls_name($constructor_param_list);
+                    // Add the public constructor, const for fieldless variants so they can be default values
+                    $(if !variant_obj.has_fields() { const }) $variant_dart_cls_name($constructor_param_list);
 
                     // Keep the private constructor used by `read`
                     $variant_dart_cls_name._($(for (i, field) in variant_obj.fields().iter().enumerate() => $(field_type(field, type_helper)) this.$(field_name(field, i)), ));
@@ -218,7 +218,7 @@ pub fn generate_enum(obj: &Enum, type_helper: &dyn TypeHelperRenderer) -> dart::
                         $(for code in &field_read_code => $code)
                         return LiftRetVal($variant_dart_cls_name._(
                             $(for (i, field) in variant_obj.fields().iter().enumerate() => $(field_name(field, i)),)
-                        ), new_offset);
+                        ), new_offset - buf.offsetInBytes);
                     }
 
                     @override
@@ -240,15 +240,43 @@ pub fn generate_enum(obj: &Enum, type_helper: &dyn TypeHelperRenderer) -> dart::
 
                         $(for code in &field_write_code => $code)
 
-                        return new_offset;
+                        return new_offset - buf.offsetInBytes;
                     }
 
-                    $to_string_method
+                    $value_members
+
+                    $(if is_serializable => $(json::variant_members(variant_obj, field_name, type_helper.get_config())))
                 }
             });
         }
 
-        let is_error_enum = type_helper.get_ci().is_name_used_as_error(obj.name());
+        let unknown_cls_name = unknown_variant.as_ref().map(|name| format!("{}{}", name, dart_cls_name));
+        let unknown_read = match &unknown_cls_name {
+            Some(unknown_cls_name) => {
+                let json_members = if is_serializable {
+                    json::unknown_variant_members(dart_cls_name)
+                } else {
+                    quote!()
+                };
+                variants.push(generate_unknown_variant(unknown_cls_name, dart_cls_name, is_flat_error, json_members));
+                quote!(return $unknown_cls_name.read(index, subview).copyWithOffset(4);)
+            }
+            None => quote!(throw UniffiInternalError(UniffiInternalError.unexpectedEnumCase, "Unable to determine enum variant");),
+        };
+        // Lifted on its own, everything after the index belongs to the variant, so an unknown
+        // one is lifted whatever fields it has
+        let lift_body = match &unknown_cls_name {
+            Some(unknown_cls_name) if !is_flat_error => quote! {
+                final buf = buffer.asUint8List();
+                final index = buf.buffer.asByteData(buf.offsetInBytes).getInt32(0);
+                if (index < 1 || index > $(obj.variants().len())) {
+                    return $unknown_cls_name(index);
+                }
+                return $ffi_converter_name.read(buf).value;
+            },
+            _ => quote!(return $ffi_converter_name.read(buffer.asUint8List()).value;),
+        };
+
         let implements_exception = if is_error_enum {
             quote!( implements Exception)
         } else {
@@ -273,8 +301,23 @@ pub fn generate_enum(obj: &Enum, type_helper: &dyn TypeHelperRenderer) -> dart::
             quote!()
         };
 
+        let base_members = if is_flat_error {
+            quote! {
+                final String message;
+
+                $dart_cls_name(this.message);
+            }
+        } else {
+            quote!(const $dart_cls_name();)
+        };
+
         quote! {
-            abstract class $dart_cls_name $implements_exception {
+            $(DartCodeOracle::doc_comment(obj.docstring()))
+            sealed class $dart_cls_name $implements_exception {
+                $base_members
+
+                $(if is_serializable => $(json::enum_base_members(obj, dart_cls_name, type_helper.get_config())))
+
                 RustBuffer lower();
                 int allocationSize();
                 int write( Uint8List buf);
@@ -282,7 +325,7 @@ pub fn generate_enum(obj: &Enum, type_helper: &dyn TypeHelperRenderer) -> dart::
 
             class $ffi_converter_name {
                 static $dart_cls_name lift( RustBuffer buffer) {
-                    return $ffi_converter_name.read(buffer.asUint8List()).value;
+                    $lift_body
                 }
 
                 static LiftRetVal<$dart_cls_name> read( Uint8List buf) {
@@ -291,9 +334,10 @@ pub fn generate_enum(obj: &Enum, type_helper: &dyn TypeHelperRenderer) -> dart::
                     switch(index) {
                         $(for (index, variant) in obj.variants().iter().enumerate() =>
                         case $(index + 1):
-                            return $(format!("{}{}", DartCodeOracle::class_name(variant.name()), dart_cls_name)).read(subview);
+                            return $(format!("{}{}", DartCodeOracle::class_name(variant.name()), dart_cls_name)).read(subview).copyWithOffset(4);
                         )
-                        default:  throw UniffiInternalError(UniffiInternalError.unexpectedEnumCase, "Unable to determine enum variant");
+                        default:
+                            $unknown_read
                     }
                 }
 
@@ -316,3 +360,263 @@ pub fn generate_enum(obj: &Enum, type_helper: &dyn TypeHelperRenderer) -> dart::
         }
     }
 }
+
+// The name of the case holding variants unknown to these bindings, avoiding a clash with a
+// variant of the same name.
+fn unknown_variant_name(obj: &Enum) -> String {
+    if obj.variants().iter().any(|v| DartCodeOracle::class_name(v.name()) == "Unknown") {
+        "UniffiUnknown".into()
+    } else {
+        "Unknown".into()
+    }
+}
+
+// Flat enums whose Rust discriminants were given explicitly become enhanced enums exposing them
+// as `value`. The converter keeps using the variant's position on the wire.
+fn generate_flat_enum(
+    obj: &Enum,
+    dart_cls_name: &str,
+    unknown_variant: Option<&str>,
+    json_members: dart::Tokens,
+) -> dart::Tokens {
+    let discriminants = (0..obj.variants().len())
+        .map(|index| match obj.variant_discr(index).expect("Invalid enum discriminant") {
+            Literal::UInt(v, _, _) => v.to_string(),
+            Literal::Int(v, _, _) => v.to_string(),
+            discr => unreachable!("Unexpected discriminant {:?}", discr),
+        })
+        .collect::<Vec<_>>();
+    let has_explicit_discriminants = obj.variant_discr_type().is_some()
+        || discriminants.iter().enumerate().any(|(index, discr)| *discr != index.to_string());
+
+    if let Some(unknown_variant) = unknown_variant {
+        let discriminants = has_explicit_discriminants.then_some(&discriminants[..]);
+        return generate_open_flat_enum(obj, dart_cls_name, unknown_variant, discriminants, json_members);
+    }
+
+    if !has_explicit_discriminants {
+        return quote! {
+            $(DartCodeOracle::doc_comment(obj.docstring()))
+            enum $dart_cls_name {
+                $(for variant in obj.variants() {
+                    $(DartCodeOracle::doc_comment(variant.docstring()))
+                    $(DartCodeOracle::enum_variant_name(variant.name())),
+                })
+                ;
+
+                $json_members
+            }
+        };
+    }
+
+    quote! {
+        $(DartCodeOracle::doc_comment(obj.docstring()))
+        enum $dart_cls_name {
+            $(for (variant, discr) in obj.variants().iter().zip(&discriminants) {
+                $(DartCodeOracle::doc_comment(variant.docstring()))
+                $(DartCodeOracle::enum_variant_name(variant.name()))($discr),
+            })
+            ;
+
+            final int value;
+
+            const $dart_cls_name(this.value);
+
+            $(generate_from_value(obj, dart_cls_name, &discriminants))
+
+            $json_members
+        }
+    }
+}
+
+fn generate_from_value(obj: &Enum, dart_cls_name: &str, discriminants: &[String]) -> dart::Tokens {
+    quote! {
+        static $dart_cls_name fromValue(int value) {
+            switch (value) {
+                $(for (variant, discr) in obj.variants().iter().zip(discriminants) =>
+                case $discr:
+                    return $dart_cls_name.$(DartCodeOracle::enum_variant_name(variant.name()));
+                )
+                default:
+                    throw ArgumentError.value(value, "value", $(format!("\"No {} with this value\"", dart_cls_name)));
+            }
+        }
+    }
+}
+
+// A Dart enum can't hold variants it doesn't declare, so flat enums with an unknown case become
+// a class with a constant per variant. Every instance keeps its raw index from the wire, which
+// lets unknown variants be lowered back as they were lifted.
+fn generate_open_flat_enum(
+    obj: &Enum,
+    dart_cls_name: &str,
+    unknown_variant: &str,
+    discriminants: Option<&[String]>,
+    json_members: dart::Tokens,
+) -> dart::Tokens {
+    let unknown_name = &DartCodeOracle::enum_variant_name(unknown_variant);
+    let variants = obj.variants().iter().enumerate().map(|(index, variant)| {
+        let variant_name = DartCodeOracle::enum_variant_name(variant.name());
+        let name_literal = format!("\"{variant_name}\"");
+        let value = discriminants.map(|d| quote!(, $(&d[index])));
+        quote! {
+            $(DartCodeOracle::doc_comment(variant.docstring()))
+            static const $(&variant_name) = $dart_cls_name._($(index + 1), $name_literal$value);
+        }
+    });
+    let (value_field, value_param, unknown_value) = match discriminants {
+        // The unknown case has no discriminant of its own
+        Some(_) => (quote!(final int? value;), quote!(, this.value), quote!(, value = null)),
+        None => (quote!(), quote!(), quote!()),
+    };
+    let unknown_to_string = format!("\"{dart_cls_name}.{unknown_name}($rawIndex)\"");
+    let known_to_string = format!("\"{dart_cls_name}.$name\"");
+    quote! {
+        $(DartCodeOracle::doc_comment(obj.docstring()))
+        @immutable
+        final class $dart_cls_name {
+            $(for v in variants => $v)
+
+            $(DartCodeOracle::doc_comment(Some("The variants known to these bindings.")))
+            static const values = [$(for v in obj.variants() join (, ) => $(DartCodeOracle::enum_variant_name(v.name())))];
+
+            $(DartCodeOracle::doc_comment(Some("The index of the variant on the wire, including for unknown variants.")))
+            final int rawIndex;
+
+            final String name;
+
+            $value_field
+
+            const $dart_cls_name._(this.rawIndex, this.name$value_param);
+
+            $(DartCodeOracle::doc_comment(Some("A variant added by a newer version of the Rust library.")))
+            const $dart_cls_name.$unknown_name(this.rawIndex) : name = $(format!("\"{unknown_name}\"")) $unknown_value;
+
+            bool get isUnknown => rawIndex < 1 || rawIndex > values.length;
+
+            $(if let Some(discriminants) = discriminants => $(generate_from_value(obj, dart_cls_name, discriminants)))
+
+            @override
+            bool operator ==(Object other) {
+                return other is $dart_cls_name && rawIndex == other.rawIndex;
+            }
+
+            @override
+            int get hashCode => rawIndex.hashCode;
+
+            @override
+            String toString() {
+                return isUnknown ? $unknown_to_string : $known_to_string;
+            }
+
+            $json_members
+        }
+    }
+}
+
+// The raw index of an unknown variant is kept so that it can be lowered again, without its
+// fields. Those can't be decoded, nor skipped, so nested in another value (a sequence, a record
+// field, ...) an unknown variant can only be read when nothing follows it. Lifted on its own
+// it always succeeds, see the converter's `lift`.
+fn generate_unknown_variant(
+    unknown_cls_name: &str,
+    dart_cls_name: &str,
+    is_flat_error: bool,
+    json_members: dart::Tokens,
+) -> dart::Tokens {
+    let (constructor, read_value, to_string) = if is_flat_error {
+        (
+            quote!($unknown_cls_name(this.rawIndex, String message) : super(message);),
+            quote! {
+                final message_lifted = FfiConverterString.read(buf);
+                return LiftRetVal($unknown_cls_name(index, message_lifted.value), message_lifted.bytesRead);
+            },
+            format!("\"{}($rawIndex): $message\"", unknown_cls_name),
+        )
+    } else {
+        (
+            quote!(const $unknown_cls_name(this.rawIndex);),
+            quote! {
+                if (buf.lengthInBytes > 0) {
+                    throw UniffiInternalError(UniffiInternalError.unexpectedEnumCase, $(format!("\"Unable to skip over unknown {dart_cls_name} variant $index\"")));
+                }
+                return LiftRetVal($unknown_cls_name(index), 0);
+            },
+            format!("\"{}($rawIndex)\"", unknown_cls_name),
+        )
+    };
+    quote! {
+        final class $unknown_cls_name extends $dart_cls_name {
+            final int rawIndex;
+
+            $constructor
+
+            static LiftRetVal<$unknown_cls_name> read(int index, Uint8List buf) {
+                $read_value
+            }
+
+            @override
+            RustBuffer lower() {
+                final buf = Uint8List(allocationSize());
+                write(buf);
+                return toRustBuffer(buf);
+            }
+
+            @override
+            int allocationSize() {
+                return 4;
+            }
+
+            @override
+            int write( Uint8List buf) {
+                buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, rawIndex);
+                return 4;
+            }
+
+            @override
+            String toString() {
+                return $to_string;
+            }
+
+            $json_members
+        }
+    }
+}
+
+// Flat error variants are lifted with the `Display` message Rust serializes after the
+// variant index, but Rust only reads the index back when one is lowered.
+fn generate_flat_error_variant(variant_dart_cls_name: &str, dart_cls_name: &str, index: usize) -> dart::Tokens {
+    quote! {
+        final class $variant_dart_cls_name extends $dart_cls_name {
+            $variant_dart_cls_name(String message) : super(message);
+
+            static LiftRetVal<$variant_dart_cls_name> read( Uint8List buf) {
+                final message_lifted = FfiConverterString.read(buf);
+                return LiftRetVal($variant_dart_cls_name(message_lifted.value), message_lifted.bytesRead);
+            }
+
+            @override
+            RustBuffer lower() {
+                final buf = Uint8List(allocationSize());
+                write(buf);
+                return toRustBuffer(buf);
+            }
+
+            @override
+            int allocationSize() {
+                return 4;
+            }
+
+            @override
+            int write( Uint8List buf) {
+                buf.buffer.asByteData(buf.offsetInBytes).setInt32(0, $(index + 1)); // write index into first position;
+                return 4;
+            }
+
+            @override
+            String toString() {
+                return $(format!("\"{}: $message\"", variant_dart_cls_name));
+            }
+        }
+    }
+}
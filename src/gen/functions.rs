@@ -1,19 +1,18 @@
 use genco::prelude::*;
-use uniffi_bindgen::interface::{AsType, Function};
+use uniffi_bindgen::interface::Function;
 use heck::ToLowerCamelCase;
 
 use crate::gen::oracle::DartCodeOracle;
-use crate::gen::render::AsRenderable;
 
 use super::oracle::AsCodeType;
 use super::render::TypeHelperRenderer;
 
 pub fn generate_function(func: &Function, type_helper: &dyn TypeHelperRenderer) -> dart::Tokens {
-    let args = quote!($(for arg in &func.arguments() => $(&arg.as_renderable().render_type(&arg.as_type(), type_helper)) $(DartCodeOracle::var_name(arg.name())),));
+    let args = DartCodeOracle::arguments(&func.arguments(), type_helper);
 
     let (ret, lifter) = if let Some(ret) = func.return_type() {
         (
-            ret.as_renderable().render_type(ret, type_helper),
+            DartCodeOracle::return_type(ret, type_helper),
             quote!($(ret.as_codetype().lift())),
         )
     } else {
@@ -31,12 +30,15 @@ pub fn generate_function(func: &Function, type_helper: &dyn TypeHelperRenderer)
     };
 
     // Use centralized callback-aware argument lowering
+    let doc = DartCodeOracle::doc_comment(func.docstring());
+
     if func.is_async() {
         quote!(
+            $doc
             Future<$ret> $(DartCodeOracle::fn_name(func.name()))($args) {
                 return uniffiRustCallAsync(
                   () => $(DartCodeOracle::find_lib_instance()).$(func.ffi_func().name())(
-                    $(for arg in &func.arguments() => $(DartCodeOracle::lower_arg_with_callback_handling(arg)),)
+                    $(for arg in &func.arguments() => $(DartCodeOracle::lower_arg_with_callback_handling(arg, type_helper)),)
                   ),
                   $(DartCodeOracle::async_poll(func, type_helper.get_ci())),
                   $(DartCodeOracle::async_complete(func, type_helper.get_ci())),
@@ -49,19 +51,21 @@ pub fn generate_function(func: &Function, type_helper: &dyn TypeHelperRenderer)
     } else {
         if ret == quote!(void) {
             quote!(
+                $(&doc)
                 $ret $(DartCodeOracle::fn_name(func.name()))($args) {
                     return rustCall((status) {
                         $(DartCodeOracle::find_lib_instance()).$(func.ffi_func().name())(
-                            $(for arg in &func.arguments() => $(DartCodeOracle::lower_arg_with_callback_handling(arg)),) status
+                            $(for arg in &func.arguments() => $(DartCodeOracle::lower_arg_with_callback_handling(arg, type_helper)),) status
                         );
                     }, $error_handler);
                 }
             )
         } else {
             quote!(
+                $(&doc)
                 $ret $(DartCodeOracle::fn_name(func.name()))($args) {
                     return rustCall((status) => $lifter($(DartCodeOracle::find_lib_instance()).$(func.ffi_func().name())(
-                        $(for arg in &func.arguments() => $(DartCodeOracle::lower_arg_with_callback_handling(arg)),) status
+                        $(for arg in &func.arguments() => $(DartCodeOracle::lower_arg_with_callback_handling(arg, type_helper)),) status
                     )), $error_handler);
                 }
             )
@@ -0,0 +1,289 @@
+//! `toJson()` / `fromJson()` support for records and enums, enabled through
+//! `json_serialization = true`.
+//!
+//! Records become JSON objects keyed by their Rust field names, enums become objects tagged with
+//! the Rust variant name under `"type"`. `u64` values exposed as `BigInt` and timestamps are
+//! encoded as strings, durations as microseconds and bytes as base64. Maps keyed by strings become
+//! JSON objects, any other map becomes a list of `[key, value]` pairs. Custom types take the form
+//! of their builtin type.
+
+use std::collections::HashSet;
+
+use genco::prelude::*;
+use uniffi_bindgen::interface::{AsType, Enum, Field, Record, Type, Variant};
+use uniffi_bindgen::ComponentInterface;
+
+use super::oracle::DartCodeOracle;
+use super::records;
+use super::Config;
+
+/// Whether `toJson()` / `fromJson()` are generated for the record or enum `name`.
+pub fn is_serializable(name: &str, ci: &ComponentInterface, config: &Config) -> bool {
+    if !config.json_serialization() {
+        return false;
+    }
+    let ty = match (ci.get_record_definition(name), ci.get_enum_definition(name)) {
+        (Some(record), _) => record.as_type(),
+        (None, Some(enum_)) => enum_.as_type(),
+        (None, None) => return false,
+    };
+    type_is_serializable(&ty, ci, &mut HashSet::new())
+}
+
+// Objects, callbacks, errors and types from other crates have no JSON form, and neither does
+// anything containing them. Types already being checked further up are assumed to be fine,
+// which keeps recursive types from looping.
+fn type_is_serializable(ty: &Type, ci: &ComponentInterface, seen: &mut HashSet<String>) -> bool {
+    if ci.is_external(ty) {
+        return false;
+    }
+    match ty {
+        Type::Object { .. } | Type::CallbackInterface { .. } => false,
+        Type::Optional { inner_type } | Type::Sequence { inner_type } => {
+            type_is_serializable(inner_type, ci, seen)
+        }
+        Type::Map { key_type, value_type } => {
+            type_is_serializable(key_type, ci, seen) && type_is_serializable(value_type, ci, seen)
+        }
+        Type::Custom { builtin, .. } => type_is_serializable(builtin, ci, seen),
+        Type::Record { name, .. } => {
+            if !seen.insert(name.clone()) {
+                return true;
+            }
+            ci.get_record_definition(name).is_some_and(|record| {
+                record.fields().iter().all(|f| type_is_serializable(&f.as_type(), ci, seen))
+            })
+        }
+        Type::Enum { name, .. } => {
+            if !seen.insert(name.clone()) {
+                return true;
+            }
+            !ci.is_name_used_as_error(name)
+                && ci.get_enum_definition(name).is_some_and(|enum_| {
+                    enum_.variants().iter().all(|v| {
+                        v.fields().iter().all(|f| type_is_serializable(&f.as_type(), ci, seen))
+                    })
+                })
+        }
+        _ => true,
+    }
+}
+
+// Maps with these keys become JSON objects
+fn is_string(ty: &Type) -> bool {
+    match ty {
+        Type::String => true,
+        Type::Custom { builtin, .. } => is_string(builtin),
+        _ => false,
+    }
+}
+
+/// The Dart expression converting `value` of type `ty` into its JSON form.
+fn encode(ty: &Type, value: &str, config: &Config) -> String {
+    match ty {
+        Type::UInt64 if config.u64_as_bigint() => format!("{value}.toString()"),
+        Type::Bytes => format!("base64Encode({value})"),
+        Type::Duration => format!("{value}.inMicroseconds"),
+        Type::Timestamp => format!("{value}.toUtc().toIso8601String()"),
+        Type::Record { .. } | Type::Enum { .. } => format!("{value}.toJson()"),
+        Type::Optional { inner_type } => match encode(inner_type, "v", config) {
+            inner if inner == "v" => value.to_string(),
+            inner => format!("uniffiJsonOptional({value}, (v) => {inner})"),
+        },
+        Type::Sequence { inner_type } => match encode(inner_type, "v", config) {
+            inner if inner == "v" => value.to_string(),
+            inner => format!("{value}.map((v) => {inner}).toList()"),
+        },
+        Type::Map { key_type, value_type } if is_string(key_type) => {
+            match (encode(key_type, "k", config), encode(value_type, "v", config)) {
+                (key, inner) if key == "k" && inner == "v" => value.to_string(),
+                (key, inner) => format!("{value}.map((k, v) => MapEntry({key}, {inner}))"),
+            }
+        }
+        Type::Map { key_type, value_type } => format!(
+            "{value}.entries.map((e) => [{}, {}]).toList()",
+            encode(key_type, "e.key", config),
+            encode(value_type, "e.value", config),
+        ),
+        Type::Custom { name, builtin, .. } => match config.custom_type(name) {
+            Some(custom) => encode(builtin, &custom.lower(value), config),
+            None => encode(builtin, value, config),
+        },
+        _ => value.to_string(),
+    }
+}
+
+/// The Dart expression converting the JSON value `json` back into a value of type `ty`.
+fn decode(ty: &Type, json: &str, config: &Config) -> String {
+    match ty {
+        Type::UInt64 if config.u64_as_bigint() => format!("BigInt.parse({json} as String)"),
+        Type::Int8
+        | Type::UInt8
+        | Type::Int16
+        | Type::UInt16
+        | Type::Int32
+        | Type::UInt32
+        | Type::Int64
+        | Type::UInt64 => format!("{json} as int"),
+        Type::Float32 | Type::Float64 => format!("({json} as num).toDouble()"),
+        Type::Boolean => format!("{json} as bool"),
+        Type::String => format!("{json} as String"),
+        Type::Bytes => format!("base64Decode({json} as String)"),
+        Type::Duration => format!("Duration(microseconds: {json} as int)"),
+        Type::Timestamp => format!("DateTime.parse({json} as String)"),
+        Type::Record { name, .. } | Type::Enum { name, .. } => format!(
+            "{}.fromJson({json} as Map<String, dynamic>)",
+            DartCodeOracle::class_name(name)
+        ),
+        Type::Optional { inner_type } => {
+            format!("uniffiJsonOptional({json}, (v) => {})", decode(inner_type, "v", config))
+        }
+        Type::Sequence { inner_type } => {
+            format!("({json} as List).map((v) => {}).toList()", decode(inner_type, "v", config))
+        }
+        Type::Map { key_type, value_type } if is_string(key_type) => format!(
+            "({json} as Map<String, dynamic>).map((k, v) => MapEntry({}, {}))",
+            decode(key_type, "k", config),
+            decode(value_type, "v", config)
+        ),
+        Type::Map { key_type, value_type } => format!(
+            "Map.fromEntries(({json} as List).map((e) => MapEntry({}, {})))",
+            decode(key_type, "e[0]", config),
+            decode(value_type, "e[1]", config),
+        ),
+        Type::Custom { name, builtin, .. } => match config.custom_type(name) {
+            Some(custom) => custom.lift(&format!("({})", decode(builtin, json, config))),
+            None => decode(builtin, json, config),
+        },
+        _ => unreachable!("{ty:?} has no JSON form"),
+    }
+}
+
+fn json_key(name: &str) -> String {
+    format!("\"{name}\"")
+}
+
+// Nameless variant fields are keyed like their Dart counterparts
+fn variant_field_key(field: &Field, field_num: usize) -> String {
+    if field.name().is_empty() {
+        json_key(&format!("v{field_num}"))
+    } else {
+        json_key(field.name())
+    }
+}
+
+pub fn record_members(obj: &Record, cls_name: &str, config: &Config) -> dart::Tokens {
+    let entries = obj.fields().iter().map(|f| {
+        let value = encode(&f.as_type(), &DartCodeOracle::var_name(f.name()), config);
+        quote!($(json_key(f.name())): $value)
+    });
+    let args = obj.fields().iter().map(|f| {
+        quote!($(decode(&f.as_type(), &format!("json[{}]", json_key(f.name())), config)))
+    });
+    quote! {
+        Map<String, dynamic> toJson() {
+            return {$(for e in entries join (, ) => $e)};
+        }
+
+        factory $cls_name.fromJson(Map<String, dynamic> json) {
+            return $(records::constructor_call(obj, cls_name, args, config));
+        }
+    }
+}
+
+fn unknown_to_json(cls_name: &str) -> String {
+    format!("throw UnsupportedError(\"An unknown {cls_name} variant has no JSON form\")")
+}
+
+fn unknown_from_json(cls_name: &str) -> String {
+    format!("throw ArgumentError.value(json[\"type\"], \"json\", \"No {cls_name} variant with this type\")")
+}
+
+/// With an unknown case, flat enums are classes keeping the raw index of their variant, see
+/// `enums::generate_open_flat_enum`.
+pub fn flat_enum_members(obj: &Enum, cls_name: &str, unknown_variant: Option<&str>) -> dart::Tokens {
+    let to_json = match unknown_variant {
+        Some(_) => quote! {
+            switch (rawIndex) {
+                $(for (index, variant) in obj.variants().iter().enumerate() =>
+                case $(index + 1):
+                    return {"type": $(json_key(variant.name()))};
+                )
+                default:
+                    $(unknown_to_json(cls_name));
+            }
+        },
+        None => quote! {
+            switch (this) {
+                $(for variant in obj.variants() =>
+                case $cls_name.$(DartCodeOracle::enum_variant_name(variant.name())):
+                    return {"type": $(json_key(variant.name()))};
+                )
+            }
+        },
+    };
+    quote! {
+        Map<String, dynamic> toJson() {
+            $to_json
+        }
+
+        factory $cls_name.fromJson(Map<String, dynamic> json) {
+            switch (json["type"]) {
+                $(for variant in obj.variants() =>
+                case $(json_key(variant.name())):
+                    return $cls_name.$(DartCodeOracle::enum_variant_name(variant.name()));
+                )
+                default:
+                    $(unknown_from_json(cls_name));
+            }
+        }
+    }
+}
+
+/// `toJson()` is declared on the sealed base class, `fromJson()` dispatches on the tag to the
+/// positional constructor `_` of each variant class.
+pub fn enum_base_members(obj: &Enum, cls_name: &str, config: &Config) -> dart::Tokens {
+    let cases = obj.variants().iter().map(|variant| {
+        let variant_cls_name = format!("{}{}", DartCodeOracle::class_name(variant.name()), cls_name);
+        let args = variant.fields().iter().enumerate().map(|(i, f)| {
+            decode(&f.as_type(), &format!("json[{}]", variant_field_key(f, i)), config)
+        });
+        quote! {
+            case $(json_key(variant.name())):
+                return $variant_cls_name._($(for a in args join (, ) => $a));
+        }
+    });
+    quote! {
+        Map<String, dynamic> toJson();
+
+        factory $cls_name.fromJson(Map<String, dynamic> json) {
+            switch (json["type"]) {
+                $(for c in cases => $c)
+                default:
+                    $(unknown_from_json(cls_name));
+            }
+        }
+    }
+}
+
+pub fn variant_members(variant: &Variant, field_name: fn(&Field, usize) -> String, config: &Config) -> dart::Tokens {
+    let entries = variant.fields().iter().enumerate().map(|(i, f)| {
+        let value = encode(&f.as_type(), &field_name(f, i), config);
+        quote!(, $(variant_field_key(f, i)): $value)
+    });
+    quote! {
+        @override
+        Map<String, dynamic> toJson() {
+            return {"type": $(json_key(variant.name()))$(for e in entries => $e)};
+        }
+    }
+}
+
+pub fn unknown_variant_members(cls_name: &str) -> dart::Tokens {
+    quote! {
+        @override
+        Map<String, dynamic> toJson() {
+            $(unknown_to_json(cls_name));
+        }
+    }
+}
@@ -1,3 +1,5 @@
+use std::borrow::Cow;
+use std::collections::BTreeSet;
 use std::collections::HashMap;
 use std::collections::HashSet;
 use std::io::Read;
@@ -22,6 +24,7 @@ mod compounds;
 mod custom;
 mod enums;
 mod functions;
+mod json;
 mod objects;
 mod oracle;
 mod primitives;
@@ -38,6 +41,69 @@ pub struct Config {
     cdylib_name: Option<String>,
     #[serde(default)]
     external_packages: HashMap<String, String>,
+    #[serde(default)]
+    custom_types: HashMap<String, CustomTypeConfig>,
+    #[serde(default)]
+    u64_as_bigint: bool,
+    #[serde(default)]
+    unknown_enum_variants: bool,
+    #[serde(default)]
+    json_serialization: bool,
+    #[serde(default)]
+    custom_extension_types: bool,
+    #[serde(default)]
+    object_interfaces: bool,
+    #[serde(default)]
+    object_identity: bool,
+}
+
+/// How a custom type is represented on the Dart side, configured through
+/// `[bindings.dart.custom_types.<Name>]` in `uniffi.toml`.
+///
+/// `lift` and `lower` are Dart expressions in which `{}` stands for the value being converted,
+/// e.g. `lift = "Uri.parse({})"` and `lower = "{}.toString()"`.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct CustomTypeConfig {
+    type_name: Option<String>,
+    #[serde(default)]
+    imports: Vec<String>,
+    lift: String,
+    lower: String,
+    #[serde(skip)]
+    extension_type: bool,
+}
+
+impl CustomTypeConfig {
+    /// A zero-cost `extension type` wrapping the builtin representation as `value`.
+    fn extension_type(type_name: String) -> Self {
+        CustomTypeConfig {
+            lift: format!("{type_name}({{}})"),
+            type_name: Some(type_name),
+            imports: vec![],
+            lower: "{}.value".into(),
+            extension_type: true,
+        }
+    }
+
+    pub fn is_extension_type(&self) -> bool {
+        self.extension_type
+    }
+
+    pub fn type_name(&self) -> Option<&str> {
+        self.type_name.as_deref()
+    }
+
+    pub fn imports(&self) -> &[String] {
+        &self.imports
+    }
+
+    pub fn lift(&self, value: &str) -> String {
+        self.lift.replace("{}", value)
+    }
+
+    pub fn lower(&self, value: &str) -> String {
+        self.lower.replace("{}", value)
+    }
 }
 
 impl From<&ComponentInterface> for Config {
@@ -46,6 +112,13 @@ impl From<&ComponentInterface> for Config {
             package_name: Some(ci.namespace().to_owned()),
             cdylib_name: Some(ci.namespace().to_owned()),
             external_packages: HashMap::new(),
+            custom_types: HashMap::new(),
+            u64_as_bigint: false,
+            unknown_enum_variants: false,
+            json_serialization: false,
+            custom_extension_types: false,
+            object_interfaces: false,
+            object_identity: false,
         }
     }
 }
@@ -66,6 +139,81 @@ impl Config {
             "uniffi".into()
         }
     }
+
+    /// The URI under which the bindings of an external crate are imported.
+    ///
+    /// Crates listed in `external_packages` resolve to `package:<mapped>/<namespace>.dart`,
+    /// anything else is expected to be generated next to this file.
+    pub fn external_import(&self, crate_name: &str, namespace: &str) -> String {
+        match self.external_packages.get(crate_name) {
+            Some(package) => format!("package:{package}/{namespace}.dart"),
+            None => format!("{namespace}.dart"),
+        }
+    }
+
+    /// Whether `u64` is exposed as `BigInt` rather than `int`.
+    ///
+    /// Dart's `int` is a signed 64-bit integer, so values above `2^63 - 1` wrap around to
+    /// negative numbers unless this is enabled through `u64_as_bigint = true`.
+    pub fn u64_as_bigint(&self) -> bool {
+        self.u64_as_bigint
+    }
+
+    /// Whether `#[non_exhaustive]` enums get an `unknown` case for variants added by newer
+    /// versions of the Rust library, instead of failing to lift them.
+    ///
+    /// Unknown variants of data enums can't be skipped over, so nested in another value they
+    /// can only be lifted when nothing follows them, see the README.
+    pub fn unknown_enum_variants(&self) -> bool {
+        self.unknown_enum_variants
+    }
+
+    /// Whether records and enums get `toJson()` and `fromJson()`, enabled through
+    /// `json_serialization = true`.
+    pub fn json_serialization(&self) -> bool {
+        self.json_serialization
+    }
+
+    /// Whether every object `Foo` also gets an abstract `FooInterface` it implements, enabled
+    /// through `object_interfaces = true`.
+    ///
+    /// Objects returned from Rust are then typed against the interface, so Dart code can
+    /// substitute fakes for them without loading the library.
+    pub fn object_interfaces(&self) -> bool {
+        self.object_interfaces
+    }
+
+    /// Whether lifting the same Rust object twice returns the same Dart instance, enabled
+    /// through `object_identity = true`.
+    ///
+    /// Live instances are tracked through weak references keyed by the address of the Rust
+    /// allocation, so that `identical` and Dart-side caches keyed by objects work as expected.
+    pub fn object_identity(&self) -> bool {
+        self.object_identity
+    }
+
+    /// How the custom type `name` is represented, if not simply as an alias of its builtin type.
+    ///
+    /// Without an explicit `[bindings.dart.custom_types.<Name>]` entry, custom types become
+    /// extension types when `custom_extension_types = true`, so that e.g. a `Handle` can't be
+    /// passed where an `int` is expected.
+    pub fn custom_type(&self, name: &str) -> Option<Cow<'_, CustomTypeConfig>> {
+        match self.custom_types.get(name) {
+            Some(config) => Some(Cow::Borrowed(config)),
+            None if self.custom_extension_types => {
+                Some(Cow::Owned(CustomTypeConfig::extension_type(DartCodeOracle::class_name(name))))
+            }
+            None => None,
+        }
+    }
+
+    /// Imports required by the configured custom types, sorted and deduplicated.
+    pub fn custom_type_imports(&self) -> BTreeSet<&str> {
+        self.custom_types
+            .values()
+            .flat_map(|c| c.imports().iter().map(String::as_str))
+            .collect()
+    }
 }
 
 
@@ -78,7 +226,7 @@ pub struct DartWrapper<'a> {
 
 impl<'a> DartWrapper<'a> {
     pub fn new(ci: &'a ComponentInterface, config: &'a Config) -> Self {
-        let type_renderer = TypeHelpersRenderer::new(ci);
+        let type_renderer = TypeHelpersRenderer::new(ci, config);
         DartWrapper {
             ci,
             config,
@@ -268,7 +416,7 @@ impl BindgenCrateConfigSupplier for LocalConfigSupplier {
         let mut reader = std::io::BufReader::new(file);
         let mut content = String::new();
         reader.read_to_string(&mut content)?;
-        return Ok(content);
+        Ok(content)
     }
 }
 
@@ -285,7 +433,7 @@ pub fn generate_dart_bindings(
             None,
             &DartBindingGenerator {},
             &LocalConfigSupplier(udl_file.to_string()),
-            None,
+            config_file_override,
             out_dir_override.unwrap(),
             false,
         )?;
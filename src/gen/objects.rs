@@ -3,10 +3,11 @@ use std::fmt::Debug;
 
 use std::string::ToString;
 use heck::ToLowerCamelCase;
-use crate::gen::callback_interface::{generate_callback_functions, generate_callback_interface, generate_callback_interface_vtable_init_function, generate_callback_vtable_interface};
+use crate::gen::callback_interface::{generate_callback_functions, generate_callback_interface_vtable_init_function, generate_callback_methods_definitions, generate_callback_methods_signatures, generate_callback_vtable_interface};
 use crate::gen::CodeType;
 use uniffi_bindgen::backend::Literal;
-use uniffi_bindgen::interface::{AsType, Method, Object, ObjectImpl};
+use uniffi_bindgen::interface::{AsType, Method, Object, ObjectImpl, UniffiTrait};
+use uniffi_bindgen::ComponentInterface;
 
 use crate::gen::oracle::{AsCodeType, DartCodeOracle};
 use crate::gen::render::AsRenderable;
@@ -35,55 +36,46 @@ impl CodeType for ObjectCodeType {
         self.id.to_string()
     }
 
-    fn literal(&self, _literal: &Literal) -> String {
+    fn literal(&self, _literal: &Literal, _ci: &ComponentInterface) -> String {
         unreachable!();
     }
 
     fn ffi_converter_name(&self) -> String {
         match self.imp {
             ObjectImpl::Struct => self.canonical_name().to_string(), // Objects will use factory methods
-            ObjectImpl::CallbackTrait => format!("FfiConverterCallbackInterface{}", self.id),
-            ObjectImpl::Trait => todo!("trait objects not supported"),
+            ObjectImpl::Trait | ObjectImpl::CallbackTrait => format!("FfiConverter{}", self.type_label()),
         }
     }
 }
 
 impl Renderable for ObjectCodeType {
-    fn render_type_helper(&self, type_helper: &dyn TypeHelperRenderer) -> dart::Tokens {
-        if type_helper.check(&self.id) {
-            quote!()
-        } else if let Some(obj) = type_helper.get_object(&self.id) {
-            generate_object(obj, type_helper)
-        } else {
-            unreachable!()
-        }
+    // Every object is rendered exactly once from its definition (see `TypeHelpersRenderer`).
+    fn render_type_helper(&self, _type_helper: &dyn TypeHelperRenderer) -> dart::Tokens {
+        quote!()
     }
 }
 pub fn generate_object(obj: &Object, type_helper: &dyn TypeHelperRenderer) -> dart::Tokens {
     type_helper.include_once_check(obj.name(), &obj.as_type());
 
-    if obj.has_callback_interface() {
-        let interface = generate_callback_interface(obj.name(), &obj.as_codetype().ffi_converter_name(), &obj.methods(), type_helper);
-        let vtable_interface = generate_callback_vtable_interface(obj.name(), &obj.methods());
-        let functions = generate_callback_functions(obj.name(), &obj.methods(), type_helper);
-        let vtable_init = generate_callback_interface_vtable_init_function(
-            obj.name(),
-            &obj.methods(),
-            type_helper.get_ci().namespace_for_type(&obj.as_type()).expect("object should have namespace")
-        );
-        return quote!(
-            $interface
-            $vtable_interface
-            $functions
-            $vtable_init
-        )
-    }
-
-    let cls_name = &DartCodeOracle::class_name(obj.name());
+    // Trait interfaces are exposed as an abstract class, with the Rust-backed implementation
+    // living in `<Name>Impl`. Regular objects are a single concrete class.
+    let interface_name = &DartCodeOracle::class_name(obj.name());
+    let cls_name = &if obj.is_trait_interface() {
+        format!("{}Impl", interface_name)
+    } else {
+        interface_name.to_string()
+    };
     let finalizer_cls_name = &format!("{}Finalizer", cls_name);
     let lib_instance = &DartCodeOracle::find_lib_instance();
     let ffi_object_free_name = obj.ffi_object_free().name();
     let ffi_object_clone_name = obj.ffi_object_clone().name();
+    let object_identity = type_helper.get_config().object_identity();
+    // With `object_identity`, every live instance is registered under its pointer address
+    let track_instance = if object_identity {
+        quote!(_uniffiInstances[_ptr.address] = WeakReference(this);)
+    } else {
+        quote!()
+    };
 
     // Stream workaround, make it more elegant later
 
@@ -113,12 +105,18 @@ pub fn generate_object(obj: &Object, type_helper: &dyn TypeHelperRenderer) -> da
             quote!(null)
         };
 
-        let dart_params = quote!($(for arg in constructor.arguments() =>
-            $(DartCodeOracle::dart_type_label(Some(&arg.as_type()))) $(DartCodeOracle::var_name(arg.name())),
-        ));
+        let dart_params = DartCodeOracle::parameters(constructor.arguments().iter().map(|arg| {
+            DartCodeOracle::parameter(
+                quote!($(DartCodeOracle::type_label(&arg.as_type(), type_helper.get_config()))),
+                &DartCodeOracle::var_name(arg.name()),
+                &arg.as_type(),
+                arg.default_value(),
+                type_helper,
+            )
+        }));
 
         let ffi_call_args = quote!($(for arg in constructor.arguments() =>
-            $(DartCodeOracle::type_lower_fn(&arg.as_type(), quote!($(DartCodeOracle::var_name(arg.name()))))),) 
+            $(DartCodeOracle::lower_arg_with_callback_handling(arg, type_helper)),) 
         );
         
         // Ensure argument types are included
@@ -126,8 +124,34 @@ pub fn generate_object(obj: &Object, type_helper: &dyn TypeHelperRenderer) -> da
             type_helper.include_once_check(&arg.as_codetype().canonical_name(), &arg.as_type());
         }
 
+        // Dart constructors can't await, so async ones become static factories, with the
+        // primary constructor named `create`
+        if constructor.is_async() {
+            let factory_name = if constructor_name == "new" {
+                "create".to_string()
+            } else {
+                DartCodeOracle::fn_name(constructor_name)
+            };
+            return quote! {
+                $(DartCodeOracle::doc_comment(constructor.docstring()))
+                static Future<$cls_name> $factory_name($dart_params) {
+                    return uniffiRustCallAsync(
+                        () => $lib_instance.$ffi_func_name(
+                            $ffi_call_args
+                        ),
+                        $(DartCodeOracle::async_poll(constructor, type_helper.get_ci())),
+                        $(DartCodeOracle::async_complete(constructor, type_helper.get_ci())),
+                        $(DartCodeOracle::async_free(constructor, type_helper.get_ci())),
+                        $cls_name.lift,
+                        $error_handler,
+                    );
+                }
+            };
+        }
+
         quote! {
             // Public constructor
+            $(DartCodeOracle::doc_comment(constructor.docstring()))
             $dart_constructor_decl($dart_params) : _ptr = rustCall((status) =>
                 $lib_instance.$ffi_func_name(
                     $ffi_call_args status
@@ -135,6 +159,7 @@ pub fn generate_object(obj: &Object, type_helper: &dyn TypeHelperRenderer) -> da
                 $error_handler
             ) {
                  _$finalizer_cls_name.attach(this, _ptr, detach: this);
+                 $(&track_instance)
             }
         }
     });
@@ -144,13 +169,18 @@ pub fn generate_object(obj: &Object, type_helper: &dyn TypeHelperRenderer) -> da
     
     let error_handler_class = if is_error_interface {
         // Generate error handlers for specific error interfaces
-        let error_handler_name = format!("{}ErrorHandler", cls_name);
-        let instance_name = cls_name.to_lower_camel_case();
+        let error_handler_name = format!("{}ErrorHandler", interface_name);
+        let instance_name = interface_name.to_lower_camel_case();
+        let error_reader = if obj.is_trait_interface() {
+            obj.as_codetype().ffi_converter_name()
+        } else {
+            cls_name.to_string()
+        };
         quote! {
             class $(&error_handler_name) extends UniffiRustCallStatusErrorHandler {
                 @override
                 Exception lift(RustBuffer errorBuf) {
-                    return $(cls_name).read(errorBuf.asUint8List()).value;
+                    return $(&error_reader).read(errorBuf.asUint8List()).value as Exception;
                 }
             }
 
@@ -160,38 +190,79 @@ pub fn generate_object(obj: &Object, type_helper: &dyn TypeHelperRenderer) -> da
         quote!()
     };
 
-    let implements_exception = if is_error_interface {
-        quote!( implements Exception)
-    } else {
-        quote!()
-    };
+    // With `object_interfaces`, regular objects also implement an abstract `<Name>Interface`
+    // that Dart code can implement to fake them
+    let object_interface = !obj.is_trait_interface() && type_helper.get_config().object_interfaces();
+    let object_interface_name = &DartCodeOracle::object_interface_name(obj.name());
 
-    
-    // Generate toString() method for error interfaces
-    let to_string_method: dart::Tokens = if is_error_interface && !obj.is_trait_interface() {
-        // Only generate toString for regular error interfaces, skip trait interfaces for now
-        let dart_class_name = format!("\"{}\"", cls_name);
-        quote! {
-            @override
-            String toString() {
-                return $(&dart_class_name);
-            }
+    // `Finalizable` keeps instances alive until FFI calls using their `_ptr` have returned
+    let mut implemented = Vec::new();
+    if obj.is_trait_interface() {
+        implemented.push(interface_name.to_string());
+    } else {
+        if object_interface {
+            implemented.push(object_interface_name.to_string());
+        }
+        if is_error_interface {
+            implemented.push("Exception".to_string());
         }
+    }
+    implemented.push("Finalizable".to_string());
+
+
+    let trait_methods = generate_uniffi_traits(obj, cls_name, is_error_interface && !obj.is_trait_interface());
+
+    // Lifting a pointer that already has a live instance returns that instance, releasing the
+    // strong count handed over with the pointer. Entries of collected instances are dropped by
+    // the finalizer through `_uniffiForget`, unless a newer instance has been lifted for the
+    // same pointer in the meantime.
+    let (identity_cache, lift_body, forget_disposed, forget_collected) = if object_identity {
+        (
+            quote! {
+                static final _uniffiInstances = <int, WeakReference<$cls_name>>{};
+
+                static void _uniffiForget(int address) {
+                    if (_uniffiInstances[address]?.target == null) {
+                        _uniffiInstances.remove(address);
+                    }
+                }
+            },
+            quote! {
+                final existing = _uniffiInstances[ptr.address]?.target;
+                if (existing != null) {
+                    rustCall((status) => $lib_instance.$ffi_object_free_name(ptr, status));
+                    return existing;
+                }
+                return $cls_name._(ptr);
+            },
+            quote!(_uniffiInstances.remove(_ptr.address);),
+            quote!($cls_name._uniffiForget(ptr.address);),
+        )
     } else {
-        quote!()
+        (quote!(), quote!(return $cls_name._(ptr);), quote!(), quote!())
     };
 
     quote! {
+        // Frees the Rust object once its instance is collected. A `NativeFinalizer` can't be
+        // bound to the free function, which takes a `RustCallStatus*` besides the pointer, so
+        // objects still alive when the isolate shuts down are not freed (see TODO.md).
         final _$finalizer_cls_name = Finalizer<Pointer<Void>>((ptr) {
-          rustCall((status) => $lib_instance.$ffi_object_free_name(ptr, status));
+            $forget_collected
+            uniffiFreeFromFinalizer($lib_instance.$ffi_object_free_name, ptr);
         });
 
-        class $cls_name $implements_exception {
+        $(if object_interface => $(generate_object_interface(obj, object_interface_name, type_helper)))
+
+        $(DartCodeOracle::doc_comment(obj.docstring()))
+        class $cls_name implements $(for i in &implemented join (, ) => $i) {
             late final Pointer<Void> _ptr;
+            bool _disposed = false;
+            $identity_cache
 
             // Private constructor for internal use / lift
             $cls_name._(this._ptr) {
                 _$finalizer_cls_name.attach(this, _ptr, detach: this);
+                $(&track_instance)
             }
 
             // Public constructors generated from UDL
@@ -199,14 +270,19 @@ pub fn generate_object(obj: &Object, type_helper: &dyn TypeHelperRenderer) -> da
 
             // Factory for lifting pointers
             factory $cls_name.lift(Pointer<Void> ptr) {
-                return $cls_name._(ptr);
+                $lift_body
             }
 
             static Pointer<Void> lower($cls_name value) {
                 return value.uniffiClonePointer();
             }
 
+            // Every call into Rust clones the pointer first, which makes this the single place
+            // to reject disposed instances
             Pointer<Void> uniffiClonePointer() {
+                if (_disposed) {
+                    throw StateError($(format!("\"{cls_name} has already been disposed\"")));
+                }
                 return rustCall((status) => $lib_instance.$ffi_object_clone_name(_ptr, status));
             }
 
@@ -227,30 +303,280 @@ pub fn generate_object(obj: &Object, type_helper: &dyn TypeHelperRenderer) -> da
                 return 8;
             }
 
+            $(DartCodeOracle::doc_comment(Some("Whether [dispose] has been called, after which the object can no longer be used.")))
+            bool get isDisposed => _disposed;
+
+            $(DartCodeOracle::doc_comment(Some("Frees the Rust object right away instead of leaving it to the finalizer.\n\nCalling this more than once has no effect.")))
             void dispose() {
+                if (_disposed) {
+                    return;
+                }
+                _disposed = true;
                 _$finalizer_cls_name.detach(this);
+                $forget_disposed
                 rustCall((status) => $lib_instance.$ffi_object_free_name(_ptr, status));
             }
 
-            $to_string_method
+            $(DartCodeOracle::doc_comment(Some("Runs [action] with this object and disposes it afterwards, once the returned\nfuture completes if [action] is asynchronous.")))
+            R using<R>(R Function($cls_name value) action) {
+                final R result;
+                try {
+                    result = action(this);
+                } catch (_) {
+                    dispose();
+                    rethrow;
+                }
+                if (result is Future) {
+                    return result.whenComplete(dispose) as R;
+                }
+                dispose();
+                return result;
+            }
+
+            $trait_methods
 
-            $(for mt in &obj.methods() => $(generate_method(mt, type_helper)))
+            $(for mt in &obj.methods() => $(generate_method(mt, !obj.is_trait_interface(), type_helper)))
         }
 
         $error_handler_class
 
         $(stream_glue)
+
+        $(if obj.is_trait_interface() => $(generate_trait_interface(obj, cls_name, is_error_interface, type_helper)))
     }
 }
 
+/// Renders the abstract interface of a regular object, declaring its methods and `dispose()`.
+fn generate_object_interface(
+    obj: &Object,
+    object_interface_name: &str,
+    type_helper: &dyn TypeHelperRenderer,
+) -> dart::Tokens {
+    let methods = obj.methods().into_iter().map(|method| {
+        let ret = method
+            .return_type()
+            .map(|ret| DartCodeOracle::return_type(ret, type_helper))
+            .unwrap_or_else(|| quote!(void));
+        let ret = if method.is_async() { quote!(Future<$ret>) } else { ret };
+        quote! {
+            $(DartCodeOracle::doc_comment(method.docstring()))
+            $ret $(DartCodeOracle::fn_name(method.name()))($(DartCodeOracle::arguments(&method.arguments(), type_helper)));
+        }
+    });
+    quote! {
+        $(DartCodeOracle::doc_comment(obj.docstring()))
+        abstract interface class $object_interface_name {
+            $(for m in methods => $m)
+
+            $(DartCodeOracle::doc_comment(Some("Whether [dispose] has been called, after which the object can no longer be used.")))
+            bool get isDisposed;
+
+            $(DartCodeOracle::doc_comment(Some("Frees the underlying resources right away.")))
+            void dispose();
+
+            $(DartCodeOracle::doc_comment(Some("Runs [action] with this object and disposes it afterwards, once the returned\nfuture completes if [action] is asynchronous.")))
+            R using<R>(R Function($object_interface_name value) action);
+        }
+    }
+}
+
+/// Renders the Dart counterparts of the Rust traits exported with `#[uniffi::export(...)]` or
+/// `[Traits=(...)]`, each calling into Rust: `toString` from `Display` (or else `Debug`),
+/// `operator ==` from `Eq` and `hashCode` from `Hash`.
+///
+/// Without `Display` or `Debug`, error objects still print their class name.
+fn generate_uniffi_traits(obj: &Object, cls_name: &str, name_as_to_string: bool) -> dart::Tokens {
+    let lib_instance = &DartCodeOracle::find_lib_instance();
+    let (mut display, mut debug, mut eq, mut hash) = (None, None, None, None);
+    for uniffi_trait in obj.uniffi_traits() {
+        match uniffi_trait {
+            UniffiTrait::Display { fmt } => display = Some(fmt),
+            UniffiTrait::Debug { fmt } => debug = Some(fmt),
+            UniffiTrait::Eq { eq: method, .. } => eq = Some(method),
+            UniffiTrait::Hash { hash: method } => hash = Some(method),
+        }
+    }
+
+    let to_string = match display.or(debug) {
+        Some(fmt) => quote! {
+            @override
+            String toString() {
+                return rustCall((status) => FfiConverterString.lift($lib_instance.$(fmt.ffi_func().name())(uniffiClonePointer(), status)));
+            }
+        },
+        None if name_as_to_string => quote! {
+            @override
+            String toString() {
+                return $(format!("\"{cls_name}\""));
+            }
+        },
+        None => quote!(),
+    };
+
+    let equality = match eq {
+        Some(eq) => quote! {
+            @override
+            bool operator ==(Object other) {
+                if (identical(this, other)) {
+                    return true;
+                }
+                if (other is! $cls_name) {
+                    return false;
+                }
+                return rustCall((status) => FfiConverterBool.lift($lib_instance.$(eq.ffi_func().name())(uniffiClonePointer(), $cls_name.lower(other), status)));
+            }
+        },
+        None => quote!(),
+    };
+
+    let hash_code = match hash {
+        // The `u64` hash is truncated to a Dart `int`, which is all `hashCode` needs
+        Some(hash) => quote! {
+            @override
+            int get hashCode {
+                return rustCall((status) => $lib_instance.$(hash.ffi_func().name())(uniffiClonePointer(), status));
+            }
+        },
+        None => quote!(),
+    };
+
+    quote! {
+        $to_string
+
+        $equality
+
+        $hash_code
+    }
+}
+
+/// Renders the abstract class and converter of a trait interface, plus the callback machinery
+/// when the trait can also be implemented in Dart.
+///
+/// Handles for Dart implementations are always odd, while pointers handed out by Rust are
+/// aligned and therefore even. Lifting uses this to tell both kinds of objects apart.
+fn generate_trait_interface(
+    obj: &Object,
+    impl_cls_name: &str,
+    is_error_interface: bool,
+    type_helper: &dyn TypeHelperRenderer,
+) -> dart::Tokens {
+    let interface_name = &DartCodeOracle::class_name(obj.name());
+    let ffi_converter_name = &obj.as_codetype().ffi_converter_name();
+    let implements_exception = if is_error_interface {
+        quote!( implements Exception)
+    } else {
+        quote!()
+    };
+
+    let (foreign_state, lower_body, callback_glue) = if obj.has_callback_interface() {
+        let init_fn_name = &format!("init{}VTable", obj.name());
+        let namespace = type_helper
+            .get_ci()
+            .namespace_for_type(&obj.as_type())
+            .expect("object should have namespace");
+        (
+            quote! {
+                static final _handleMap = UniffiHandleMap<$interface_name>();
+                static bool _vtableInitialized = false;
+
+                static void _ensureVTableInitialized() {
+                    if (!_vtableInitialized) {
+                        $init_fn_name();
+                        _vtableInitialized = true;
+                    }
+                }
+            },
+            // Rust always wraps foreign trait handles in a callback proxy, so Rust-backed
+            // instances are passed as handles too and get called back through the vtable.
+            quote! {
+                _ensureVTableInitialized();
+                return Pointer<Void>.fromAddress(_handleMap.insert(value));
+            },
+            quote! {
+                $(generate_callback_methods_signatures(interface_name, &obj.methods(), type_helper))
+                $(generate_callback_vtable_interface(obj.name(), &obj.methods()))
+                $(generate_callback_functions(obj.name(), ffi_converter_name, &obj.methods(), type_helper))
+                $(generate_callback_interface_vtable_init_function(obj.name(), ffi_converter_name, &obj.methods(), namespace))
+            },
+        )
+    } else {
+        let not_implementable = format!(
+            "\"{} is implemented in Rust and cannot be implemented in Dart\"",
+            interface_name
+        );
+        (
+            quote!(),
+            quote! {
+                if (value is $impl_cls_name) {
+                    return $impl_cls_name.lower(value);
+                }
+                throw UnsupportedError($not_implementable);
+            },
+            quote!(),
+        )
+    };
+
+    let lift_foreign = if obj.has_callback_interface() {
+        quote! {
+            if (ptr.address & 1 == 1) {
+                return _handleMap.get(ptr.address);
+            }
+        }
+    } else {
+        quote!()
+    };
+
+    quote! {
+        $(DartCodeOracle::doc_comment(obj.docstring()))
+        abstract class $interface_name $implements_exception {
+            $(for m in obj.methods() => $(generate_callback_methods_definitions(m, type_helper)))
+        }
+
+        class $ffi_converter_name {
+            $foreign_state
+
+            static $interface_name lift(Pointer<Void> ptr) {
+                $lift_foreign
+                return $impl_cls_name.lift(ptr);
+            }
+
+            static Pointer<Void> lower($interface_name value) {
+                $lower_body
+            }
+
+            static int allocationSize($interface_name value) {
+                return 8;
+            }
+
+            static LiftRetVal<$interface_name> read(Uint8List buf) {
+                final handle = buf.buffer.asByteData(buf.offsetInBytes).getInt64(0);
+                return LiftRetVal(lift(Pointer<Void>.fromAddress(handle)), 8);
+            }
+
+            static int write($interface_name value, Uint8List buf) {
+                buf.buffer.asByteData(buf.offsetInBytes).setInt64(0, lower(value).address);
+                return 8;
+            }
+        }
+
+        $callback_glue
+    }
+}
+
+/// Renders a method calling into Rust. Methods of trait interfaces pass `false` for
+/// `interface_returns`, since they must keep the return types of the abstract class they implement.
 #[allow(unused_variables)]
-pub fn generate_method(func: &Method, type_helper: &dyn TypeHelperRenderer) -> dart::Tokens {
+pub fn generate_method(func: &Method, interface_returns: bool, type_helper: &dyn TypeHelperRenderer) -> dart::Tokens {
     // if func.takes_self_by_arc() {} // TODO: Do something about this condition
-    let args = quote!($(for arg in &func.arguments() => $(&arg.as_renderable().render_type(&arg.as_type(), type_helper)) $(DartCodeOracle::var_name(arg.name())),));
+    let args = DartCodeOracle::arguments(&func.arguments(), type_helper);
 
     let (ret, lifter) = if let Some(ret) = func.return_type() {
         (
-            ret.as_renderable().render_type(ret, type_helper),
+            if interface_returns {
+                DartCodeOracle::return_type(ret, type_helper)
+            } else {
+                ret.as_renderable().render_type(ret, type_helper)
+            },
             quote!($(ret.as_codetype().lift())),
         )
     } else {
@@ -267,13 +593,16 @@ pub fn generate_method(func: &Method, type_helper: &dyn TypeHelperRenderer) -> d
         quote!(null)
     };
 
+    let doc = DartCodeOracle::doc_comment(func.docstring());
+
     if func.is_async() {
         quote!(
+            $doc
             Future<$ret> $(DartCodeOracle::fn_name(func.name()))($args) {
                 return uniffiRustCallAsync(
                   () => $(DartCodeOracle::find_lib_instance()).$(func.ffi_func().name())(
                     uniffiClonePointer(),
-                    $(for arg in &func.arguments() => $(DartCodeOracle::lower_arg_with_callback_handling(arg)),)
+                    $(for arg in &func.arguments() => $(DartCodeOracle::lower_arg_with_callback_handling(arg, type_helper)),)
                   ),
                   $(DartCodeOracle::async_poll(func, type_helper.get_ci())),
                   $(DartCodeOracle::async_complete(func, type_helper.get_ci())),
@@ -287,21 +616,23 @@ pub fn generate_method(func: &Method, type_helper: &dyn TypeHelperRenderer) -> d
     } else {
         if ret == quote!(void) {
             quote!(
+                $(&doc)
                 $ret $(DartCodeOracle::fn_name(func.name()))($args) {
                     return rustCall((status) {
                         $(DartCodeOracle::find_lib_instance()).$(func.ffi_func().name())(
                             uniffiClonePointer(),
-                            $(for arg in &func.arguments() => $(DartCodeOracle::lower_arg_with_callback_handling(arg)),) status
+                            $(for arg in &func.arguments() => $(DartCodeOracle::lower_arg_with_callback_handling(arg, type_helper)),) status
                         );
                     }, $error_handler);
                 }
             )
         } else {
             quote!(
+                $(&doc)
                 $ret $(DartCodeOracle::fn_name(func.name()))($args) {
                     return rustCall((status) => $lifter($(DartCodeOracle::find_lib_instance()).$(func.ffi_func().name())(
                         uniffiClonePointer(),
-                        $(for arg in &func.arguments() => $(DartCodeOracle::lower_arg_with_callback_handling(arg)),) status
+                        $(for arg in &func.arguments() => $(DartCodeOracle::lower_arg_with_callback_handling(arg, type_helper)),) status
                     )), $error_handler);
                 }
             )
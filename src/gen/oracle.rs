@@ -2,17 +2,19 @@ use genco::lang::dart;
 use genco::quote;
 use heck::{ToLowerCamelCase, ToUpperCamelCase};
 use uniffi_bindgen::interface::ffi::ExternalFfiMetadata;
-use uniffi_bindgen::interface::{Argument, ObjectImpl};
+use uniffi_bindgen::backend::Literal;
+use uniffi_bindgen::interface::Argument;
 
 
 use crate::gen::CodeType;
-use uniffi_bindgen::interface::{AsType, Callable, FfiType, Type};
+use uniffi_bindgen::interface::{AsType, Callable, FfiType, ObjectImpl, Type};
 use uniffi_bindgen::ComponentInterface;
 
 use crate::gen::primitives;
 
-// use super::render::{AsRenderable, Renderable};
+use super::render::{AsRenderable, TypeHelperRenderer};
 use super::{callback_interface, compounds, custom, enums, objects, records};
+use super::Config;
 
 pub struct DartCodeOracle;
 
@@ -65,6 +67,21 @@ impl DartCodeOracle {
         Self::sanitize_identifier(&nm.to_lower_camel_case())
     }
 
+    /// Render a docstring from the Rust source as a `///` doc comment, or nothing without one.
+    ///
+    /// Every line gets its own `///`, so the Markdown is kept as written. Carriage returns are
+    /// dropped since Dart would end the comment at them.
+    pub fn doc_comment(docstring: Option<&str>) -> dart::Tokens {
+        let Some(docstring) = docstring else {
+            return quote!();
+        };
+        let lines = docstring.lines().map(|line| match line.replace('\r', "").trim_end() {
+            "" => "///".to_string(),
+            line => format!("/// {line}"),
+        });
+        quote!($(for line in lines join ($['\r']) => $line)$['\r'])
+    }
+
     /// Get the idiomatic Dart rendering of an FFI callback function name
     fn ffi_callback_name(nm: &str) -> String {
         format!(
@@ -78,8 +95,7 @@ impl DartCodeOracle {
         format!("Uniffi{}", name.to_upper_camel_case())
     }
 
-
-    /// Get the idiomatic Dart rendering of an exception name
+    // Get the idiomatic Dart rendering of an exception name
     // pub fn error_name(nm: &str) -> String {
     //     let name = Self::class_name(nm);
     //     match name.strip_suffix("Error") {
@@ -87,7 +103,6 @@ impl DartCodeOracle {
     //         Some(stripped) => format!("{stripped}Exception"),
     //     }
     // }
-
     pub fn find_lib_instance() -> dart::Tokens {
         quote!(_UniffiLib.instance)
     }
@@ -102,13 +117,29 @@ impl DartCodeOracle {
 
     /// Helper method to fully qualify imports of external `RustBuffer`s
     fn rust_buffer_name_with_path(module_path: &str, ci: &ComponentInterface) -> dart::Tokens {
-        let namespace = ci.namespace_for_module_path(module_path).expect("module path should exist");
+        let namespace = Self::namespace_for_module_path(module_path, ci);
         if namespace != ci.namespace() {
             return quote!($(namespace).RustBuffer);
         }
         quote!(RustBuffer)
     }
 
+    /// The namespace of the crate defining `module_path`, which is also the library prefix its
+    /// bindings are imported under.
+    ///
+    /// Outside of library mode other crates are unknown to the `ComponentInterface`, in which case
+    /// the namespace is assumed to match the crate name (as it does by default).
+    pub fn namespace_for_module_path(module_path: &str, ci: &ComponentInterface) -> String {
+        match ci.namespace_for_module_path(module_path) {
+            Ok(namespace) => namespace.to_string(),
+            Err(_) => Self::crate_name(module_path).to_string(),
+        }
+    }
+
+    pub fn crate_name(module_path: &str) -> &str {
+        module_path.split("::").next().unwrap_or(module_path)
+    }
+
     // TODO: Replace instances of `generate_ffi_dart_type` with ffi_type_label
     pub fn ffi_dart_type_label(ffi_type: Option<&FfiType>, ci: &ComponentInterface) -> dart::Tokens {
         if let Some(ret_type) = ffi_type {
@@ -216,10 +247,11 @@ impl DartCodeOracle {
             | Type::UInt16
             | Type::Int32
             | Type::Int64
-            | Type::UInt64
             | Type::Float32
             | Type::Float64 => inner,
-            Type::Boolean
+            // `u64` may be exposed as a `BigInt`, so it always goes through its converter.
+            Type::UInt64
+            | Type::Boolean
             | Type::Duration
             | Type::String
             | Type::Object { .. }
@@ -248,6 +280,195 @@ impl DartCodeOracle {
         quote!($(Self::find_lib_instance()).$ffi_func)
     }
 
+    /// The Dart type of a value of `type_` as seen by users of the bindings.
+    ///
+    /// Unlike `CodeType::type_label`, this honours the `u64_as_bigint` option.
+    pub fn type_label(type_: &Type, config: &Config) -> String {
+        type_.as_codetype().configured_type_label(config)
+    }
+
+    /// The name of the abstract interface generated for the object `nm` with `object_interfaces`.
+    pub fn object_interface_name(nm: &str) -> String {
+        format!("{}Interface", Self::class_name(nm))
+    }
+
+    /// The declared Dart type of a value returned from Rust.
+    ///
+    /// With `object_interfaces`, objects are returned as their interface so callers can
+    /// substitute fakes. Arguments keep the concrete class, which is needed to lower them.
+    pub fn return_type(ret: &Type, type_helper: &dyn TypeHelperRenderer) -> dart::Tokens {
+        if !type_helper.get_config().object_interfaces() || type_helper.get_ci().is_external(ret) {
+            return ret.as_renderable().render_type(ret, type_helper);
+        }
+        match ret {
+            Type::Object { name, imp: ObjectImpl::Struct, .. } => {
+                type_helper.include_once_check(name, ret);
+                quote!($(Self::object_interface_name(name)))
+            }
+            Type::Optional { inner_type } => quote!($(Self::return_type(inner_type, type_helper))?),
+            Type::Sequence { inner_type } => quote!(List<$(Self::return_type(inner_type, type_helper))>),
+            Type::Map { key_type, value_type } => quote!(Map<$(key_type.as_renderable().render_type(key_type, type_helper)), $(Self::return_type(value_type, type_helper))>),
+            _ => ret.as_renderable().render_type(ret, type_helper),
+        }
+    }
+
+    /// Whether the default value `literal` of `type_` has no const Dart form, as for `u64`
+    /// exposed as `BigInt`. Parameters with such a default are nullable instead, `null`
+    /// standing for the default.
+    pub fn has_runtime_default(type_: &Type, literal: &Literal, config: &Config) -> bool {
+        match (type_, literal) {
+            (Type::UInt64, Literal::UInt(..) | Literal::Int(..)) => config.u64_as_bigint(),
+            (Type::Optional { inner_type }, Literal::Some { inner }) => {
+                Self::has_runtime_default(inner_type, inner, config)
+            }
+            _ => false,
+        }
+    }
+
+    /// The Dart constant for a default value of `type_`, or the expression computing it for
+    /// defaults without a const form (see `has_runtime_default`).
+    pub fn default_value(type_: &Type, literal: &Literal, type_helper: &dyn TypeHelperRenderer) -> String {
+        match (type_, literal) {
+            (Type::UInt64, Literal::UInt(v, ..)) if type_helper.get_config().u64_as_bigint() => {
+                format!("BigInt.parse(\"{v}\")")
+            }
+            (Type::UInt64, Literal::Int(v, ..)) if type_helper.get_config().u64_as_bigint() => {
+                format!("BigInt.parse(\"{v}\")")
+            }
+            (Type::Optional { inner_type }, Literal::Some { inner })
+                if Self::has_runtime_default(type_, literal, type_helper.get_config()) =>
+            {
+                Self::default_value(inner_type, inner, type_helper)
+            }
+            _ => type_.as_codetype().literal(literal, type_helper.get_ci()),
+        }
+    }
+
+    /// The declaration of a parameter named `name` of `type_`, rendered as `type_label`, and
+    /// whether it is named. Parameters with a default value are named, and nullable without a
+    /// default when it has no const form, which `parameter_value` then fills in.
+    pub fn parameter(
+        type_label: dart::Tokens,
+        name: &str,
+        type_: &Type,
+        default: Option<&Literal>,
+        type_helper: &dyn TypeHelperRenderer,
+    ) -> (dart::Tokens, bool) {
+        match default {
+            None => (quote!($type_label $name), false),
+            Some(default) if Self::has_runtime_default(type_, default, type_helper.get_config()) => {
+                match type_ {
+                    Type::Optional { .. } => (quote!($type_label $name), true),
+                    _ => (quote!($type_label? $name), true),
+                }
+            }
+            Some(default) => (
+                quote!($type_label $name = $(Self::default_value(type_, default, type_helper))),
+                true,
+            ),
+        }
+    }
+
+    /// The value of a parameter declared through `parameter`.
+    pub fn parameter_value(
+        name: &str,
+        type_: &Type,
+        default: Option<&Literal>,
+        type_helper: &dyn TypeHelperRenderer,
+    ) -> dart::Tokens {
+        match default {
+            Some(default) if Self::has_runtime_default(type_, default, type_helper.get_config()) => {
+                quote!(($name ?? $(Self::default_value(type_, default, type_helper))))
+            }
+            _ => quote!($name),
+        }
+    }
+
+    /// Renders a parameter list from declarations and whether they are named, moving the named
+    /// parameters to the end.
+    pub fn parameters(params: impl IntoIterator<Item = (dart::Tokens, bool)>) -> dart::Tokens {
+        let (named, positional): (Vec<_>, Vec<_>) = params.into_iter().partition(|(_, named)| *named);
+        if named.is_empty() {
+            quote!($(for (param, _) in positional => $param,))
+        } else {
+            quote!($(for (param, _) in positional => $param,) { $(for (param, _) in named => $param,) })
+        }
+    }
+
+    /// The Dart parameters for the arguments of a function, method or constructor.
+    pub fn arguments(args: &[&Argument], type_helper: &dyn TypeHelperRenderer) -> dart::Tokens {
+        Self::parameters(args.iter().map(|arg| {
+            Self::parameter(
+                arg.as_renderable().render_type(&arg.as_type(), type_helper),
+                &Self::var_name(arg.name()),
+                &arg.as_type(),
+                arg.default_value(),
+                type_helper,
+            )
+        }))
+    }
+
+    /// `operator ==`, `hashCode` and `toString` comparing the given fields by value.
+    ///
+    /// Lists and maps are compared element by element. `toString` shows the field names unless
+    /// `named` is false, as for tuple-like enum variants, and just the class name without fields.
+    pub fn value_members(cls_name: &str, fields: &[(String, Type)], named: bool) -> dart::Tokens {
+        fn is_collection(type_: &Type) -> bool {
+            match type_ {
+                Type::Sequence { .. } | Type::Map { .. } | Type::Bytes => true,
+                Type::Optional { inner_type } => is_collection(inner_type),
+                _ => false,
+            }
+        }
+
+        let equals = fields.iter().map(|(name, type_)| {
+            if is_collection(type_) {
+                quote!(uniffiDeepEquals($name, other.$name))
+            } else {
+                quote!($name == other.$name)
+            }
+        });
+        let hash = if fields.is_empty() {
+            quote!(runtimeType.hashCode)
+        } else {
+            let parts = fields.iter().map(|(name, type_)| {
+                if is_collection(type_) {
+                    quote!(uniffiDeepHash($name))
+                } else {
+                    quote!($name)
+                }
+            });
+            quote!(Object.hashAll([$(for part in parts => $part,)]))
+        };
+        let description = if fields.is_empty() {
+            cls_name.to_string()
+        } else {
+            let fields = fields
+                .iter()
+                .map(|(name, _)| if named { format!("{name}: ${{{name}}}") } else { format!("${{{name}}}") })
+                .collect::<Vec<_>>();
+            format!("{cls_name}({})", fields.join(", "))
+        };
+
+        quote! {
+            @override
+            bool operator ==(Object other) {
+                if (identical(this, other)) {
+                    return true;
+                }
+                return other is $cls_name $(for part in equals => && $part);
+            }
+
+            @override
+            int get hashCode => $hash;
+
+            @override
+            String toString() {
+                return $(format!("\"{description}\""));
+            }
+        }
+    }
+
     /// Get the idiomatic Dart rendering of a class name based on `Type`.
     pub fn dart_type_label(type_: Option<&Type>) -> dart::Tokens {
         if let Some(ret_type) = type_ {
@@ -320,8 +541,8 @@ impl DartCodeOracle {
                 Type::Boolean => quote!(Int8),
                 Type::Bytes => quote!(RustBuffer),
                 Type::String => quote!(RustBuffer),
-                Type::Timestamp => quote!(Int64),
-                Type::Duration => quote!(Int64),
+                Type::Timestamp => quote!(RustBuffer),
+                Type::Duration => quote!(RustBuffer),
                 Type::Optional { inner_type } => match **inner_type {
                     Type::String => quote!(RustBuffer),
                     _ => quote!(RustBuffer),
@@ -329,12 +550,8 @@ impl DartCodeOracle {
                 Type::Sequence { .. } => quote!(RustBuffer),
                 Type::Map { .. } => quote!(RustBuffer),
                 Type::Object { .. } => quote!(Pointer<Void>),
-                Type::Enum { .. } => quote!(Int32),
-                Type::Record { module_path, .. } => Self::rust_buffer_name_with_path(module_path, ci),
-                Type::Custom { name, .. } => {
-                    let class_name = &DartCodeOracle::class_name(name);
-                    quote!($class_name)
-                },
+                Type::Enum { module_path, .. } | Type::Record { module_path, .. } => Self::rust_buffer_name_with_path(module_path, ci),
+                Type::Custom { builtin, .. } => Self::native_type_label(Some(builtin), ci),
                 _ => quote!(Pointer<Void>),
             }
         } else {
@@ -359,8 +576,8 @@ impl DartCodeOracle {
                 Type::Boolean => quote!(int),
                 Type::Bytes => quote!(RustBuffer),
                 Type::String => quote!(RustBuffer),
-                Type::Timestamp => quote!(int),
-                Type::Duration => quote!(int),
+                Type::Timestamp => quote!(RustBuffer),
+                Type::Duration => quote!(RustBuffer),
                 Type::Optional { inner_type } => match **inner_type {
                     Type::String => quote!(RustBuffer),
                     _ => quote!(RustBuffer),
@@ -368,12 +585,8 @@ impl DartCodeOracle {
                 Type::Sequence { .. } => quote!(RustBuffer),
                 Type::Map { .. } => quote!(RustBuffer),
                 Type::Object { .. } => quote!(Pointer<Void>),
-                Type::Enum { .. } => quote!(int),
-                Type::Record { module_path, .. } => Self::rust_buffer_name_with_path(module_path, ci),
-                Type::Custom { name, .. } => {
-                    let type_name = &DartCodeOracle::class_name(name);
-                    quote!($type_name)
-                },
+                Type::Enum { module_path, .. } | Type::Record { module_path, .. } => Self::rust_buffer_name_with_path(module_path, ci),
+                Type::Custom { builtin, .. } => Self::native_dart_type_label(Some(builtin), ci),
                 _ => quote!(dynamic),
             }
         } else {
@@ -403,7 +616,13 @@ impl DartCodeOracle {
                     quote!(RustBuffer $arg_name)
                 }
             },
-            Type::Record { module_path, .. } => quote!($(Self::rust_buffer_name_with_path(module_path, ci)) $arg_name),
+            Type::Map { .. } | Type::Timestamp | Type::Duration => quote!(RustBuffer $arg_name),
+            Type::Enum { module_path, .. } | Type::Record { module_path, .. } => quote!($(Self::rust_buffer_name_with_path(module_path, ci)) $arg_name),
+            // Custom types arrive in their builtin's native form and are wrapped by their converter
+            Type::Custom { builtin, .. } => {
+                let native_label = DartCodeOracle::native_dart_type_label(Some(builtin), ci);
+                quote!($native_label $arg_name)
+            }
             _ => {
                 let type_label = DartCodeOracle::dart_type_label(Some(arg_type));
                 quote!($type_label $arg_name)
@@ -434,18 +653,11 @@ impl DartCodeOracle {
                         }
                     )
                 } else {
+                    // The optional converter writes the presence tag itself
                     let lowered = ret_type.as_codetype().ffi_converter_name();
                     quote!(
                         final result = obj.$method_name($(for arg in &args => $arg,));
-                        if (result == null) {
-                            outReturn.ref = toRustBuffer(Uint8List.fromList([0]));
-                        } else {
-                            final lowered = $lowered.lower(result);
-                            final buffer = Uint8List(1 + lowered.length);
-                            buffer[0] = 1;
-                            buffer.setAll(1, lowered.asUint8List());
-                            outReturn.ref = toRustBuffer(buffer);
-                        }
+                        outReturn.ref = $lowered.lower(result);
                     )
                 }
             },
@@ -473,6 +685,14 @@ impl DartCodeOracle {
                     )
                 }
             },
+            ty if Self::is_scalar_return(ty) => {
+                // For numbers, and custom types built on them
+                let lowered = ret_type.as_codetype().ffi_converter_name();
+                quote!(
+                    final result = obj.$method_name($(for arg in &args => $arg,));
+                    outReturn.value = $lowered.lower(result);
+                )
+            }
             _ => {
                 // For other return types
                 let lowered = ret_type.as_codetype().ffi_converter_name();
@@ -485,10 +705,10 @@ impl DartCodeOracle {
     }
 
     // Method to get the appropriate return type for callback functions
-    pub fn callback_out_return_type(ret_type: Option<&Type>) -> dart::Tokens {
+    pub fn callback_out_return_type(ret_type: Option<&Type>, ci: &ComponentInterface) -> dart::Tokens {
         if let Some(ret) = ret_type {
             match ret {
-                Type::Boolean => quote!(Pointer<Int8>),
+                ret if Self::is_scalar_return(ret) => quote!(Pointer<$(Self::native_type_label(Some(ret), ci))>),
                 _ => quote!(Pointer<RustBuffer>)
             }
         } else {
@@ -496,6 +716,25 @@ impl DartCodeOracle {
         }
     }
 
+    // Return values written straight into the out pointer rather than through a `RustBuffer`
+    fn is_scalar_return(ret_type: &Type) -> bool {
+        match ret_type {
+            Type::UInt8
+            | Type::UInt16
+            | Type::UInt32
+            | Type::UInt64
+            | Type::Int8
+            | Type::Int16
+            | Type::Int32
+            | Type::Int64
+            | Type::Float32
+            | Type::Float64
+            | Type::Boolean => true,
+            Type::Custom { builtin, .. } => Self::is_scalar_return(builtin),
+            _ => false,
+        }
+    }
+
     // Method to handle void return values in callbacks
     pub fn callback_void_handling(method_name: &str, args: Vec<dart::Tokens>) -> dart::Tokens {
         quote!(
@@ -543,14 +782,12 @@ impl DartCodeOracle {
     }
 
     /// Lower argument with special handling for callback traits
-    pub fn lower_arg_with_callback_handling(arg: &Argument) -> dart::Tokens {
-        let base_lower = Self::type_lower_fn(&arg.as_type(), quote!($(Self::var_name(arg.name()))));
-        match arg.as_type() {
-            Type::Object { imp, .. } if imp == ObjectImpl::CallbackTrait => {
-                quote!(Pointer<Void>.fromAddress($(base_lower)))
-            }
-            _ => base_lower
-        }
+    ///
+    /// Trait interface converters already hand out a `Pointer<Void>`, so every argument is
+    /// lowered through its converter.
+    pub fn lower_arg_with_callback_handling(arg: &Argument, type_helper: &dyn TypeHelperRenderer) -> dart::Tokens {
+        let value = Self::parameter_value(&Self::var_name(arg.name()), &arg.as_type(), arg.default_value(), type_helper);
+        Self::type_lower_fn(&arg.as_type(), value)
     }
 
 }
@@ -641,6 +878,7 @@ impl<T: AsType> AsCodeType for T {
             Type::Float64 => Box::new(primitives::Float64CodeType),
             Type::Boolean => Box::new(primitives::BooleanCodeType),
             Type::String => Box::new(primitives::StringCodeType),
+            Type::Timestamp => Box::new(primitives::TimestampCodeType),
             Type::Duration => Box::new(primitives::DurationCodeType),
             Type::Bytes => Box::new(primitives::BytesCodeType),
             Type::Object { name, imp, .. } => Box::new(objects::ObjectCodeType::new(name, imp)),
@@ -652,11 +890,18 @@ impl<T: AsType> AsCodeType for T {
                 self.as_type(),
                 *inner_type,
             )),
+            Type::Map {
+                key_type,
+                value_type,
+            } => Box::new(compounds::MapCodeType::new(
+                self.as_type(),
+                *key_type,
+                *value_type,
+            )),
             Type::Enum { name, .. } => Box::new(enums::EnumCodeType::new(name)),
             Type::Record {name, .. } => Box::new(records::RecordCodeType::new(name)),
             Type::CallbackInterface { name, .. } => Box::new(callback_interface::CallbackInterfaceCodeType::new(name, self.as_type())),
             Type::Custom { name, module_path, builtin } => Box::new(custom::CustomCodeType::new(name, module_path, builtin)),
-            _ => todo!("As Type for Type::{:?}", self.as_type()),
         }
     }
 }
@@ -1,5 +1,16 @@
 macro_rules! impl_code_type_for_primitive {
+    // `u64` is exposed as a `BigInt` with `u64_as_bigint`
+    (UInt64CodeType, $class_name:literal, $canonical_name:literal) => {
+        impl_code_type_for_primitive!(@impl UInt64CodeType, $class_name, $canonical_name, {
+            fn configured_type_label(&self, config: &crate::gen::Config) -> String {
+                if config.u64_as_bigint() { "BigInt".into() } else { $class_name.into() }
+            }
+        });
+    };
     ($T:ty, $class_name:literal, $canonical_name:literal) => {
+        impl_code_type_for_primitive!(@impl $T, $class_name, $canonical_name, {});
+    };
+    (@impl $T:ty, $class_name:literal, $canonical_name:literal, { $($extra:item)* }) => {
         paste! {
             #[derive(Debug)]
             pub struct $T;
@@ -9,7 +20,9 @@ macro_rules! impl_code_type_for_primitive {
                     $class_name.into()
                 }
 
-                fn literal(&self, literal: &uniffi_bindgen::backend::Literal) -> String {
+                $($extra)*
+
+                fn literal(&self, literal: &uniffi_bindgen::backend::Literal, _ci: &uniffi_bindgen::ComponentInterface) -> String {
                     $crate::gen::primitives::render_literal(&literal)
                 }
 
@@ -26,6 +39,46 @@ macro_rules! impl_code_type_for_primitive {
 }
 
 macro_rules! impl_renderable_for_primitive {
+    // The `int` backed converter shared by the numeric primitives
+    (@int $self:ident, $canonical_name:literal, $allocation_size:literal) => {{
+        use crate::gen::code_type::CodeType;
+        let endian = (if $canonical_name.contains("Float") {
+            ", Endian.little"
+        } else {
+            ""
+        });
+
+        let cl_name = &$self.ffi_converter_name();
+        let type_signature = &$self.type_label();
+        let conversion_name = &$canonical_name
+                            .replace("UInt", "Uint")
+                            .replace("Double", "Float");
+
+        quote! {
+            class $cl_name {
+                // According to generated funtion signatures, we won't need to convert number types
+                static $type_signature lift($type_signature value) => value;
+
+
+                static LiftRetVal<$type_signature> read(Uint8List buf) {
+                    return LiftRetVal(buf.buffer.asByteData(buf.offsetInBytes).get$conversion_name(0), $allocation_size);
+                }
+
+                static $type_signature lower($type_signature value) => value;
+
+
+                static int allocationSize([$type_signature value = 0]) {
+                  return $allocation_size;
+                }
+
+                static int write($type_signature value, Uint8List buf) {
+                    buf.buffer.asByteData(buf.offsetInBytes).set$conversion_name(0, value$endian);
+                    return $cl_name.allocationSize();
+                }
+
+            }
+        }
+    }};
     (BytesCodeType, $class_name:literal, $canonical_name:literal) => {
         impl Renderable for BytesCodeType {
             fn render_type_helper(&self, _type_helper: &dyn TypeHelperRenderer) -> dart::Tokens {
@@ -68,47 +121,49 @@ macro_rules! impl_renderable_for_primitive {
             }
         }
     };
-    ($T:ty, $class_name:literal, $canonical_name:literal, $allocation_size:literal) => {
-        impl Renderable for $T {
-            fn render_type_helper(&self, _type_helper: &dyn TypeHelperRenderer) -> dart::Tokens {
-                use crate::gen::code_type::CodeType;
-                let endian = (if $canonical_name.contains("Float") {
-                    ", Endian.little"
-                } else {
-                    ""
-                });
+    (UInt64CodeType, $class_name:literal, $canonical_name:literal, $allocation_size:literal) => {
+        impl Renderable for UInt64CodeType {
+            fn render_type_helper(&self, type_helper: &dyn TypeHelperRenderer) -> dart::Tokens {
+                if !type_helper.get_config().u64_as_bigint() {
+                    return impl_renderable_for_primitive!(@int self, $canonical_name, $allocation_size);
+                }
 
                 let cl_name = &self.ffi_converter_name();
-                let type_signature = &self.type_label();
-                let conversion_name = &$canonical_name
-                                    .replace("UInt", "Uint")
-                                    .replace("Double", "Float");
 
+                // The native side still passes an `int`, which holds the same 64 bits as the `BigInt`
                 quote! {
                     class $cl_name {
-                        // According to generated funtion signatures, we won't need to convert number types
-                        static $type_signature lift($type_signature value) => value;
-
+                        static BigInt lift(int value) => BigInt.from(value).toUnsigned(64);
 
-                        static LiftRetVal<$type_signature> read(Uint8List buf) {
-                            return LiftRetVal(buf.buffer.asByteData(buf.offsetInBytes).get$conversion_name(0), $allocation_size);
+                        static LiftRetVal<BigInt> read(Uint8List buf) {
+                            return LiftRetVal(lift(buf.buffer.asByteData(buf.offsetInBytes).getUint64(0)), $allocation_size);
                         }
 
-                        static $type_signature lower($type_signature value) => value;
-
+                        static int lower(BigInt value) {
+                            if (value.isNegative || value.bitLength > 64) {
+                                throw ArgumentError.value(value, "value", "Out of range for u64");
+                            }
+                            return value.toSigned(64).toInt();
+                        }
 
-                        static int allocationSize([$type_signature value = 0]) {
+                        static int allocationSize([BigInt? value]) {
                           return $allocation_size;
                         }
 
-                        static int write($type_signature value, Uint8List buf) {
-                            buf.buffer.asByteData(buf.offsetInBytes).set$conversion_name(0, value$endian);
+                        static int write(BigInt value, Uint8List buf) {
+                            buf.buffer.asByteData(buf.offsetInBytes).setUint64(0, lower(value));
                             return $cl_name.allocationSize();
                         }
-
                     }
                 }
             }
         }
     };
+    ($T:ty, $class_name:literal, $canonical_name:literal, $allocation_size:literal) => {
+        impl Renderable for $T {
+            fn render_type_helper(&self, _type_helper: &dyn TypeHelperRenderer) -> dart::Tokens {
+                impl_renderable_for_primitive!(@int self, $canonical_name, $allocation_size)
+            }
+        }
+    };
 }
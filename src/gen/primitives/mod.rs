@@ -3,6 +3,7 @@ mod macros;
 mod boolean;
 mod duration;
 mod string;
+mod timestamp;
 
 use crate::gen::render::{Renderable, TypeHelperRenderer};
 use crate::gen::CodeType;
@@ -14,6 +15,7 @@ use uniffi_bindgen::interface::{Radix, Type};
 pub use boolean::BooleanCodeType;
 pub use duration::DurationCodeType;
 pub use string::StringCodeType;
+pub use timestamp::TimestampCodeType;
 
 fn render_literal(literal: &Literal) -> String {
     fn typed_number(type_: &Type, num_str: String) -> String {
@@ -24,6 +26,7 @@ fn render_literal(literal: &Literal) -> String {
             | Type::UInt16
             | Type::Int32
             | Type::UInt32
+            | Type::Int64
             | Type::UInt64
             | Type::Float32
             | Type::Float64
@@ -34,7 +37,13 @@ fn render_literal(literal: &Literal) -> String {
 
     match literal {
         Literal::Boolean(v) => format!("{}", v),
-        Literal::String(s) => format!("'{}'", s),
+        Literal::String(s) => format!(
+            "'{}'",
+            s.replace('\\', "\\\\")
+                .replace('\'', "\\'")
+                .replace('$', "\\$")
+                .replace('\n', "\\n")
+        ),
         Literal::Int(i, radix, type_) => typed_number(
             type_,
             match radix {
@@ -5,6 +5,8 @@ use crate::gen::{
 
 use genco::lang::dart;
 use crate::gen::CodeType;
+use uniffi_bindgen::backend::Literal;
+use uniffi_bindgen::ComponentInterface;
 
 #[derive(Debug)]
 pub struct StringCodeType;
@@ -12,6 +14,10 @@ impl CodeType for StringCodeType {
     fn type_label(&self) -> String {
         "String".to_owned()
     }
+
+    fn literal(&self, literal: &Literal, _ci: &ComponentInterface) -> String {
+        super::render_literal(literal)
+    }
 }
 
 impl Renderable for StringCodeType {
@@ -0,0 +1,56 @@
+use crate::gen::{
+    quote,
+    render::{Renderable, TypeHelperRenderer},
+};
+
+use super::paste;
+use genco::lang::dart;
+
+impl_code_type_for_primitive!(TimestampCodeType, "DateTime", "Timestamp");
+
+// Timestamps travel as an i64 count of seconds since the Unix epoch followed by a u32 count of
+// nanoseconds. As in UniFFI itself, the seconds carry the sign and the nanoseconds are the
+// sub-second part of the magnitude, so dates before 1970 read back correctly.
+//
+// Dart's `DateTime` only has microsecond precision, so sub-microsecond nanoseconds are truncated
+// towards the epoch when lifting. Lifted values are always in UTC.
+impl Renderable for TimestampCodeType {
+    fn render_type_helper(&self, _type_helper: &dyn TypeHelperRenderer) -> dart::Tokens {
+        quote! {
+            class FfiConverterTimestamp {
+                static DateTime lift( RustBuffer buf) {
+                    return FfiConverterTimestamp.read(buf.asUint8List()).value;
+                }
+
+                static RustBuffer lower( DateTime value) {
+                    final buf = Uint8List(allocationSize(value));
+                    write(value, buf);
+                    return toRustBuffer(buf);
+                }
+
+                static LiftRetVal<DateTime> read( Uint8List buf) {
+                    final bytes = buf.buffer.asByteData(buf.offsetInBytes, 12);
+                    final seconds = bytes.getInt64(0);
+                    final nanos = bytes.getUint32(8);
+                    final magnitude = seconds.abs() * 1000000 + nanos ~/ 1000;
+                    final micros = seconds < 0 ? -magnitude : magnitude;
+                    return LiftRetVal(DateTime.fromMicrosecondsSinceEpoch(micros, isUtc: true), 12);
+                }
+
+                static int allocationSize([DateTime? value]) {
+                    return 12;
+                }
+
+                static int write( DateTime value, Uint8List buf) {
+                    final bytes = buf.buffer.asByteData(buf.offsetInBytes, 12);
+                    final micros = value.microsecondsSinceEpoch;
+                    final magnitude = micros.abs();
+                    final seconds = magnitude ~/ 1000000;
+                    bytes.setInt64(0, micros < 0 ? -seconds : seconds);
+                    bytes.setUint32(8, (magnitude % 1000000) * 1000);
+                    return 12;
+                }
+            }
+        }
+    }
+}
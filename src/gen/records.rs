@@ -1,10 +1,12 @@
+use super::json;
 use super::oracle::{AsCodeType, DartCodeOracle};
-use super::render::{Renderable, TypeHelperRenderer};
-use super::types::generate_type;
+use super::render::{AsRenderable, Renderable, TypeHelperRenderer};
+use super::Config;
 use genco::prelude::*;
 use crate::gen::CodeType;
 use uniffi_bindgen::backend::Literal;
-use uniffi_bindgen::interface::{AsType, Record};
+use uniffi_bindgen::interface::{AsType, Field, Record, Type};
+use uniffi_bindgen::ComponentInterface;
 
 #[derive(Debug)]
 pub struct RecordCodeType {
@@ -29,20 +31,16 @@ impl CodeType for RecordCodeType {
         self.id.to_string()
     }
 
-    fn literal(&self, _literal: &Literal) -> String {
-        todo!("literal not implemented for RecordCodeType");
+    fn literal(&self, _literal: &Literal, _ci: &ComponentInterface) -> String {
+        unreachable!("records have no literal form");
     }
 }
 
 impl Renderable for RecordCodeType {
-    fn render_type_helper(&self, type_helper: &dyn TypeHelperRenderer) -> dart::Tokens {
-        if type_helper.check(&self.id) {
-            quote!()
-        } else if let Some(record_) = type_helper.get_record(&self.id) {
-            generate_record(record_, type_helper)
-        } else {
-            todo!("render_type_helper not implemented for unknown record type");
-        }
+    // Every record is rendered exactly once from its definition (see `TypeHelpersRenderer`),
+    // so references to it, including recursive ones from its own fields, render nothing here.
+    fn render_type_helper(&self, _type_helper: &dyn TypeHelperRenderer) -> dart::Tokens {
+        quote!()
     }
 }
 
@@ -53,10 +51,27 @@ pub fn generate_record(obj: &Record, type_helper: &dyn TypeHelperRenderer) -> da
         type_helper.include_once_check(&f.as_codetype().canonical_name(), &f.as_type());
     }
     quote! {
+        $(DartCodeOracle::doc_comment(obj.docstring()))
+        @immutable
         class $cls_name {
-            $(for f in obj.fields() => final $(generate_type(&f.as_type())) $(DartCodeOracle::var_name(f.name()));)
+            $(for f in obj.fields() {
+                $(DartCodeOracle::doc_comment(f.docstring()))
+                final $(f.as_renderable().render_type(&f.as_type(), type_helper)) $(DartCodeOracle::var_name(f.name()));
+            })
+
+            $(generate_constructor(obj, cls_name, type_helper))
+
+            $(generate_copy_with(obj, cls_name, type_helper))
+
+            $(if json::is_serializable(obj.name(), type_helper.get_ci(), type_helper.get_config()) {
+                $(json::record_members(obj, cls_name, type_helper.get_config()))
+            })
 
-            $(cls_name)($(for f in obj.fields() => this.$(DartCodeOracle::var_name(f.name())), ));
+            $(DartCodeOracle::value_members(
+                cls_name,
+                &obj.fields().iter().map(|f| (DartCodeOracle::var_name(f.name()), f.as_type())).collect::<Vec<_>>(),
+                true,
+            ))
         }
 
         class $ffi_conv_name {
@@ -72,9 +87,7 @@ pub fn generate_record(obj: &Record, type_helper: &dyn TypeHelperRenderer) -> da
                     final $(DartCodeOracle::var_name(f.name())) = $(DartCodeOracle::var_name(f.name()))_lifted.value;
                     new_offset += $(DartCodeOracle::var_name(f.name()))_lifted.bytesRead;
                 )
-                return LiftRetVal($(cls_name)(
-                    $(for f in obj.fields() => $(DartCodeOracle::var_name(f.name())),)
-                ), new_offset - buf.offsetInBytes);
+                return LiftRetVal($(constructor_call(obj, cls_name, obj.fields().iter().map(|f| quote!($(DartCodeOracle::var_name(f.name())))), type_helper.get_config())), new_offset - buf.offsetInBytes);
             }
 
             static RustBuffer lower( $cls_name value) {
@@ -99,3 +112,97 @@ pub fn generate_record(obj: &Record, type_helper: &dyn TypeHelperRenderer) -> da
         }
     }
 }
+
+fn has_runtime_default(f: &Field, config: &Config) -> bool {
+    f.default_value().is_some_and(|d| DartCodeOracle::has_runtime_default(&f.as_type(), d, config))
+}
+
+/// Renders a call constructing `obj` from `args`, one value per field in order.
+///
+/// Records with defaults that have no const form go through their private constructor, which
+/// takes the fields as they are. Their public one would turn a `null` into the default.
+pub fn constructor_call(
+    obj: &Record,
+    cls_name: &str,
+    args: impl IntoIterator<Item = dart::Tokens>,
+    config: &Config,
+) -> dart::Tokens {
+    if obj.fields().iter().any(|f| has_runtime_default(f, config)) {
+        return quote!($cls_name._($(for a in args join (, ) => $a)));
+    }
+    let args = obj.fields().iter().zip(args).map(|(f, arg)| match f.default_value() {
+        Some(_) => quote!($(DartCodeOracle::var_name(f.name())): $arg),
+        None => arg,
+    });
+    quote!($cls_name($(for a in args join (, ) => $a)))
+}
+
+// Fields whose default has no const Dart form are taken as nullable parameters and resolved in
+// the initializer list, which keeps the constructor from being const.
+fn generate_constructor(obj: &Record, cls_name: &str, type_helper: &dyn TypeHelperRenderer) -> dart::Tokens {
+    let config = type_helper.get_config();
+    let runtime_defaults = obj
+        .fields()
+        .iter()
+        .filter(|f| has_runtime_default(f, config))
+        .collect::<Vec<_>>();
+    let params = DartCodeOracle::parameters(obj.fields().iter().map(|f| {
+        let name = DartCodeOracle::var_name(f.name());
+        match f.default_value() {
+            Some(_) if runtime_defaults.iter().any(|r| r.name() == f.name()) => DartCodeOracle::parameter(
+                f.as_renderable().render_type(&f.as_type(), type_helper),
+                &name,
+                &f.as_type(),
+                f.default_value(),
+                type_helper,
+            ),
+            Some(default) => (
+                quote!(this.$(&name) = $(DartCodeOracle::default_value(&f.as_type(), default, type_helper))),
+                true,
+            ),
+            None => (quote!(this.$(&name)), false),
+        }
+    }));
+    if runtime_defaults.is_empty() {
+        return quote!(const $cls_name($params););
+    }
+    let initializers = runtime_defaults.iter().map(|f| {
+        let name = DartCodeOracle::var_name(f.name());
+        quote!($(&name) = $(DartCodeOracle::parameter_value(&name, &f.as_type(), f.default_value(), type_helper)))
+    });
+    quote! {
+        $cls_name($params) : $(for i in initializers join (, ) => $i);
+
+        const $cls_name._($(for f in obj.fields() => this.$(DartCodeOracle::var_name(f.name())),));
+    }
+}
+
+// Optional fields take a function returning the new value rather than the value itself,
+// so that `copyWith(field: () => null)` can clear them while an omitted argument keeps them.
+fn generate_copy_with(obj: &Record, cls_name: &str, type_helper: &dyn TypeHelperRenderer) -> dart::Tokens {
+    let fields = obj.fields();
+    if fields.is_empty() {
+        return quote!($cls_name copyWith() => $cls_name(););
+    }
+    let mut params: Vec<dart::Tokens> = Vec::new();
+    let mut args: Vec<dart::Tokens> = Vec::new();
+    for f in fields {
+        let ty = f.as_renderable().render_type(&f.as_type(), type_helper);
+        let name = DartCodeOracle::var_name(f.name());
+        match f.as_type() {
+            Type::Optional { .. } => {
+                params.push(quote!($ty Function()? $(&name)));
+                args.push(quote!($(&name) != null ? $(&name)() : this.$(&name)));
+            }
+            _ => {
+                params.push(quote!($ty? $(&name)));
+                args.push(quote!($(&name) ?? this.$(&name)));
+            }
+        }
+    }
+    quote! {
+        $cls_name copyWith({$(for p in params join (, ) => $p)}) {
+            return $(constructor_call(obj, cls_name, args, type_helper.get_config()));
+        }
+    }
+}
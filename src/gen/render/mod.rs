@@ -3,6 +3,7 @@ use super::{objects, oracle::AsCodeType};
 use genco::{lang::dart, quote};
 use uniffi_bindgen::interface::{AsType, Enum, Object, Record, Type};
 use uniffi_bindgen::ComponentInterface;
+use super::Config;
 
 pub trait Renderer<T> {
     fn render(&self) -> T;
@@ -10,6 +11,7 @@ pub trait Renderer<T> {
 
 pub trait TypeHelperRenderer {
     fn get_ci(&self) -> &ComponentInterface;
+    fn get_config(&self) -> &Config;
     fn include_once_check(&self, name: &str, ty: &Type) -> bool;
     fn check(&self, name: &str) -> bool;
 
@@ -29,8 +31,8 @@ pub trait Renderable {
             | Type::Int16
             | Type::UInt32
             | Type::Int32
-            | Type::UInt64
             | Type::Int64 => quote!(int),
+            Type::UInt64 => quote!($(DartCodeOracle::type_label(ty, type_helper.get_config()))),
             Type::Float32 | Type::Float64 => quote!(double),
             Type::String => quote!(String),
             Type::Boolean => quote!(bool),
@@ -49,9 +51,9 @@ pub trait Renderable {
             Type::Enum { name, .. } => quote!($(DartCodeOracle::class_name(name))),
             Type::Record { name, .. } => quote!($name),
             Type::Custom { name, .. } => quote!($name),
+            Type::Timestamp => quote!(DateTime),
             Type::Duration => quote!(Duration),
             Type::CallbackInterface { name, .. } => quote!($name),
-            _ => todo!("Type::{:?}", ty),
         };
 
         if !type_helper.include_once_check(&ty.as_codetype().canonical_name(), ty) {
@@ -83,6 +85,7 @@ impl<T: AsType> AsRenderable for T {
             Type::Float64 => Box::new(primitives::Float64CodeType),
             Type::Boolean => Box::new(primitives::BooleanCodeType),
             Type::String => Box::new(primitives::StringCodeType),
+            Type::Timestamp => Box::new(primitives::TimestampCodeType),
             Type::Duration => Box::new(primitives::DurationCodeType),
             Type::Bytes => Box::new(primitives::BytesCodeType),
             Type::Object { name, imp, .. } => Box::new(objects::ObjectCodeType::new(name, imp)),
@@ -94,11 +97,18 @@ impl<T: AsType> AsRenderable for T {
                 self.as_type(),
                 *inner_type,
             )),
+            Type::Map {
+                key_type,
+                value_type,
+            } => Box::new(compounds::MapCodeType::new(
+                self.as_type(),
+                *key_type,
+                *value_type,
+            )),
             Type::Enum { name, .. } => Box::new(enums::EnumCodeType::new(name)),
             Type::Record {name, .. } => Box::new(records::RecordCodeType::new(name)),
             Type::Custom {name, module_path, builtin } => Box::new(custom::CustomCodeType::new(name, module_path, builtin)),
             Type::CallbackInterface { name, .. } => Box::new(callback_interface::CallbackInterfaceCodeType::new(name, self.as_type())),
-            _ => todo!("Renderable for Type::{:?}", self.as_type()),
         }
     }
 }
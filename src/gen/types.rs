@@ -1,26 +1,30 @@
-use std::collections::BTreeSet;
+use std::collections::{BTreeSet, HashSet};
 use std::{cell::RefCell, collections::HashMap};
 
 use genco::prelude::*;
-use uniffi_bindgen::interface::AsType;
+use heck::ToLowerCamelCase;
+use uniffi_bindgen::interface::{AsType, ObjectImpl};
 use uniffi_bindgen::{interface::Type, ComponentInterface};
 
 
 use super::render::{AsRenderable, Renderer, TypeHelperRenderer, Renderable};
 use super::{enums, functions, objects, oracle::AsCodeType, records};
 use crate::gen::oracle::DartCodeOracle;
+use crate::gen::Config;
 
 type FunctionDefinition = dart::Tokens;
 
 pub struct TypeHelpersRenderer<'a> {
     ci: &'a ComponentInterface,
+    config: &'a Config,
     include_once_names: RefCell<HashMap<String, Type>>,
 }
 
 impl<'a> TypeHelpersRenderer<'a> {
-    pub fn new(ci: &'a ComponentInterface) -> Self {
+    pub fn new(ci: &'a ComponentInterface, config: &'a Config) -> Self {
         Self {
             ci,
+            config,
             include_once_names: RefCell::new(HashMap::new()),
         }
     }
@@ -28,6 +32,51 @@ impl<'a> TypeHelpersRenderer<'a> {
     pub fn get_include_names(&self) -> HashMap<String, Type> {
         self.include_once_names.clone().into_inner()
     }
+
+    /// Types defined by another crate are not redefined, but aliased from that crate's bindings,
+    /// so the rest of the generated code can refer to them like any local type.
+    fn render_external_type(&self, ty: &Type) -> dart::Tokens {
+        let module_path = ty.module_path().expect("external type should have module_path");
+        let namespace = &DartCodeOracle::namespace_for_module_path(module_path, self.ci);
+        let code_type = ty.as_codetype();
+        let type_label = &code_type.type_label();
+        let ffi_converter_name = &code_type.ffi_converter_name();
+
+        // Plain objects are their own converter
+        let converter_alias = if ffi_converter_name == type_label {
+            quote!()
+        } else if let Type::Object { imp: ObjectImpl::Struct, .. } = ty {
+            quote!(typedef $ffi_converter_name = $namespace.$type_label;)
+        } else {
+            quote!(typedef $ffi_converter_name = $namespace.$ffi_converter_name;)
+        };
+
+        // The external error handler expects that library's `RustBuffer`, so errors are lifted by
+        // a local handler instead.
+        let error_handler = match ty.name() {
+            Some(name) if self.ci.is_name_used_as_error(name) => {
+                let error_handler_name = &format!("{}ErrorHandler", type_label);
+                let instance_name = type_label.to_lower_camel_case();
+                quote! {
+                    class $error_handler_name extends UniffiRustCallStatusErrorHandler {
+                        @override
+                        Exception lift(RustBuffer errorBuf) {
+                            return $ffi_converter_name.read(errorBuf.asUint8List()).value as Exception;
+                        }
+                    }
+
+                    final $error_handler_name $(instance_name)ErrorHandler = $error_handler_name();
+                }
+            }
+            _ => quote!(),
+        };
+
+        quote! {
+            typedef $type_label = $namespace.$type_label;
+            $converter_alias
+            $error_handler
+        }
+    }
 }
 
 impl TypeHelperRenderer for TypeHelpersRenderer<'_> {
@@ -58,6 +107,10 @@ impl TypeHelperRenderer for TypeHelpersRenderer<'_> {
         self.ci
     }
 
+    fn get_config(&self) -> &Config {
+        self.config
+    }
+
     fn get_record(&self, name: &str) -> Option<&uniffi_bindgen::interface::Record> {
         self.ci.get_record_definition(name)
     }
@@ -76,15 +129,23 @@ impl Renderer<(FunctionDefinition, dart::Tokens)> for TypeHelpersRenderer<'_> {
             $( for obj in self.ci.object_definitions() => $(objects::generate_object(obj, self)))
         };
 
-        // Render all unique imports, sorted alphabetically
+        // Render all unique imports, sorted alphabetically. External libraries are only imported
+        // under their namespace as a prefix, so that their runtime helpers (e.g. RustBuffer vs.
+        // ext.RustBuffer) never conflict with ours.
         let modules_to_import = self.ci.iter_external_types()
-            .map(|ty| self.ci.namespace_for_type(ty).expect("external type should have module_path"))
+            .map(|ty| {
+                let module_path = ty.module_path().expect("external type should have module_path");
+                let namespace = DartCodeOracle::namespace_for_module_path(module_path, self.ci);
+                let uri = self.config.external_import(DartCodeOracle::crate_name(module_path), &namespace);
+                (namespace, uri)
+            })
             .collect::<BTreeSet<_>>();
-        // The second import statement uses a library prefix, to distinguish conflicting identifiers e.g. RustBuffer vs. ext.RustBuffer
         let imports: dart::Tokens = quote!(
-            $( for imp in modules_to_import {
-                $(format!("import \"{}.dart\"", imp));
-                $(format!("import \"{}.dart\"", imp)) as $imp;
+            $( for (namespace, uri) in modules_to_import {
+                $(format!("import \"{}\"", uri)) as $namespace;
+            })
+            $( for imp in self.config.custom_type_imports() {
+                $(format!("import \"{}\";", imp))
             })
         );
 
@@ -109,9 +170,35 @@ impl Renderer<(FunctionDefinition, dart::Tokens)> for TypeHelpersRenderer<'_> {
 
         // Let's include the string converter
         self.include_once_check(&Type::String.as_codetype().canonical_name(), &Type::String);
-        let helpers_definitions = quote! {
-            $(for (_, ty) in self.get_include_names().iter() => $(ty.as_renderable().render_type_helper(self)) )
-        };
+        // External types are aliased even when only referenced indirectly, e.g. as an error
+        for ty in self.ci.iter_external_types() {
+            self.include_once_check(&ty.as_codetype().canonical_name(), ty);
+        }
+        // Rendering a helper can register further helpers (e.g. the key and value converters of a
+        // map), so keep rendering until no new types show up.
+        let mut rendered = HashSet::new();
+        let mut helpers_definitions = quote!();
+        loop {
+            let pending = self
+                .get_include_names()
+                .into_values()
+                .filter(|ty| rendered.insert(ty.as_codetype().canonical_name()))
+                .collect::<Vec<_>>();
+            if pending.is_empty() {
+                break;
+            }
+            for ty in pending {
+                if self.ci.is_external(&ty) {
+                    helpers_definitions.append(self.render_external_type(&ty));
+                } else {
+                    helpers_definitions.append(ty.as_renderable().render_type_helper(self));
+                }
+            }
+        }
+
+        // Records and open flat enums are annotated `@immutable`, which comes from `package:meta`
+        let uses_meta = self.ci.record_definitions().next().is_some()
+            || self.ci.enum_definitions().any(|e| enums::is_open_flat_enum(e, self.ci, self.config));
 
         let types_helper_code = quote! {
             import "dart:async";
@@ -121,6 +208,7 @@ impl Renderer<(FunctionDefinition, dart::Tokens)> for TypeHelpersRenderer<'_> {
             import "dart:isolate";
             import "dart:typed_data";
             import "package:ffi/ffi.dart";
+            $(if uses_meta { import "package:meta/meta.dart"; })
             $(imports)
 
             $(types_definitions)
@@ -214,6 +302,21 @@ impl Renderer<(FunctionDefinition, dart::Tokens)> for TypeHelpersRenderer<'_> {
                 }
             }
 
+            // Frees a Rust object from a `Finalizer` callback, where exceptions can't be thrown.
+            // The call still gets a real `RustCallStatus`, so a panic while dropping the object
+            // is written there and its message released instead of being reported.
+            void uniffiFreeFromFinalizer(void Function(Pointer<Void>, Pointer<RustCallStatus>) free, Pointer<Void> ptr) {
+                final status = calloc<RustCallStatus>();
+                try {
+                    free(ptr, status);
+                    if (status.ref.code != CALL_SUCCESS) {
+                        status.ref.errorBuf.free();
+                    }
+                } finally {
+                    calloc.free(status);
+                }
+            }
+
             class NullRustCallStatusErrorHandler extends UniffiRustCallStatusErrorHandler {
                 @override
                 Exception lift(RustBuffer errorBuf) {
@@ -310,6 +413,50 @@ impl Renderer<(FunctionDefinition, dart::Tokens)> for TypeHelpersRenderer<'_> {
                 }
             }
 
+            // Structural equality for the lists and maps held by records and enum variants
+            bool uniffiDeepEquals(Object? a, Object? b) {
+                if (identical(a, b)) {
+                    return true;
+                }
+                if (a is List && b is List) {
+                    if (a.length != b.length) {
+                        return false;
+                    }
+                    for (var i = 0; i < a.length; i++) {
+                        if (!uniffiDeepEquals(a[i], b[i])) {
+                            return false;
+                        }
+                    }
+                    return true;
+                }
+                if (a is Map && b is Map) {
+                    if (a.length != b.length) {
+                        return false;
+                    }
+                    for (final key in a.keys) {
+                        if (!b.containsKey(key) || !uniffiDeepEquals(a[key], b[key])) {
+                            return false;
+                        }
+                    }
+                    return true;
+                }
+                return a == b;
+            }
+
+            int uniffiDeepHash(Object? value) {
+                if (value is List) {
+                    return Object.hashAll(value.map(uniffiDeepHash));
+                }
+                if (value is Map) {
+                    return Object.hashAllUnordered(value.entries.map((e) => Object.hash(uniffiDeepHash(e.key), uniffiDeepHash(e.value))));
+                }
+                return value.hashCode;
+            }
+
+            T? uniffiJsonOptional<S, T>(S? value, T Function(S) convert) {
+                return value == null ? null : convert(value);
+            }
+
             abstract class FfiConverter<D, F> {
                 const FfiConverter();
 
@@ -328,24 +475,6 @@ impl Renderer<(FunctionDefinition, dart::Tokens)> for TypeHelpersRenderer<'_> {
                 T lower(T value) => value;
             }
 
-            Uint8List createUint8ListFromInt(int value) {
-                int length = value.bitLength ~/ 8 + 1;
-
-                // Ensure the length is either 4 or 8
-                if (length != 4 && length != 8) {
-                length = (value < 0x100000000) ? 4 : 8;
-                }
-
-                Uint8List uint8List = Uint8List(length);
-
-                for (int i = length - 1; i >= 0; i--) {
-                uint8List[i] = value & 0xFF;
-                value >>= 8;
-                }
-
-                return uint8List;
-            }
-
             $(helpers_definitions)
 
             const int UNIFFI_RUST_FUTURE_POLL_READY = 0;
@@ -392,7 +521,7 @@ impl Renderer<(FunctionDefinition, dart::Tokens)> for TypeHelpersRenderer<'_> {
                     try {
 
                         final result = completeFunc(rustFuture, status);
-
+                        checkCallStatus(errorHandler ?? NullRustCallStatusErrorHandler(), status);
                         return liftFunc(result);
                     } finally {
                         calloc.free(status);
@@ -404,10 +533,13 @@ impl Renderer<(FunctionDefinition, dart::Tokens)> for TypeHelpersRenderer<'_> {
 
             class UniffiHandleMap<T> {
                 final Map<int, T> _map = {};
-                int _counter = 0;
+                // Handles for Dart objects are always odd, so that they can never be confused
+                // with the (aligned) pointers Rust hands out for its own objects.
+                int _counter = 1;
             
                 int insert(T obj) {
-                final handle = _counter++;
+                final handle = _counter;
+                _counter += 2;
                 _map[handle] = obj;
                 return handle;
                 }
@@ -435,27 +567,3 @@ impl Renderer<(FunctionDefinition, dart::Tokens)> for TypeHelpersRenderer<'_> {
     }
 }
 
-pub fn generate_type(ty: &Type) -> dart::Tokens {
-    match ty {
-        Type::UInt8
-        | Type::UInt32
-        | Type::Int8
-        | Type::Int16
-        | Type::Int64
-        | Type::UInt16
-        | Type::Int32
-        | Type::UInt64 => quote!(int),
-        Type::Float32 | Type::Float64 => quote!(double),
-        Type::String => quote!(String),
-        Type::Bytes => quote!(Uint8List),
-        Type::Object { name, .. } => quote!($name),
-        Type::Boolean => quote!(bool),
-        Type::Optional { inner_type } => quote!($(generate_type(inner_type))?),
-        Type::Sequence { inner_type } => quote!(List<$(generate_type(inner_type))>),
-        Type::Enum { name, .. } => quote!($(DartCodeOracle::class_name(name))),
-        Type::Duration => quote!(Duration),
-        Type::Record { name, .. } => quote!($name),
-        Type::Custom { name, .. } => quote!($name),
-        _ => todo!("Type::{:?}", ty),
-    }
-}
@@ -1,7 +1,7 @@
 use crate::gen;
 use anyhow::{bail, Result};
 use camino::{Utf8Path, Utf8PathBuf};
-use std::fs::{copy, create_dir_all, File};
+use std::fs::{copy, create_dir_all, read_to_string, File};
 use std::io::Write;
 use std::process::Command;
 use std::thread;
@@ -16,23 +16,68 @@ pub struct CompileSource {
 }
 
 pub fn run_test(fixture: &str, udl_path: &str, config_path: Option<&str>) -> Result<()> {
-    let tmp_dir = camino_tempfile::tempdir()?;
+    run_test_impl(fixture, udl_path, config_path, false)
+}
 
-    let script_path = Utf8Path::new(".").canonicalize_utf8()?;
+/// Like [`run_test`], but generates the bindings in library mode, i.e. for every crate whose
+/// components are linked into the fixture's cdylib.
+pub fn run_library_test(fixture: &str, udl_path: &str, config_path: Option<&str>) -> Result<()> {
+    run_test_impl(fixture, udl_path, config_path, true)
+}
+
+/// Generates the fixture's bindings in library mode and returns the source of the
+/// `<namespace>.dart` file, for tests that check the generated code itself.
+pub fn generate_library_bindings(
+    fixture: &str,
+    udl_path: &str,
+    config_path: Option<&str>,
+    namespace: &str,
+) -> Result<String> {
+    let tmp_dir = camino_tempfile::tempdir()?;
     let test_helper = UniFFITestHelper::new(fixture)?;
-    let out_dir = test_helper.create_out_dir(&tmp_dir, &script_path)?;
+    let out_dir = test_helper.create_out_dir(&tmp_dir, &Utf8Path::new(".").canonicalize_utf8()?)?;
+    let (udl_path, config_path) = resolve_paths(udl_path, config_path)?;
+    gen::generate_dart_bindings(
+        &udl_path,
+        config_path.as_deref(),
+        Some(&out_dir),
+        &test_helper.cdylib_path()?,
+        true,
+    )?;
+    Ok(read_to_string(out_dir.join(format!("{namespace}.dart")))?)
+}
 
+fn resolve_paths(
+    udl_path: &str,
+    config_path: Option<&str>,
+) -> Result<(Utf8PathBuf, Option<Utf8PathBuf>)> {
     let udl_path = Utf8Path::new(".").canonicalize_utf8()?.join(udl_path);
     let config_path = if let Some(path) = config_path {
         Some(Utf8Path::new(".").canonicalize_utf8()?.join(path))
     } else {
         None
     };
+    Ok((udl_path, config_path))
+}
+
+fn run_test_impl(
+    fixture: &str,
+    udl_path: &str,
+    config_path: Option<&str>,
+    library_mode: bool,
+) -> Result<()> {
+    let tmp_dir = camino_tempfile::tempdir()?;
+
+    let script_path = Utf8Path::new(".").canonicalize_utf8()?;
+    let test_helper = UniFFITestHelper::new(fixture)?;
+    let out_dir = test_helper.create_out_dir(&tmp_dir, &script_path)?;
+
+    let (udl_path, config_path) = resolve_paths(udl_path, config_path)?;
 
     println!("{out_dir}");
 
     let mut pubspec = File::create(out_dir.join("pubspec.yaml"))?;
-    pubspec.write(
+    pubspec.write_all(
         b"
     name: uniffi_test
     description: testing module for uniffi
@@ -44,6 +89,7 @@ pub fn run_test(fixture: &str, udl_path: &str, config_path: Option<&str>) -> Res
       test: ^1.24.3
     dependencies:
       ffi: ^2.0.1
+      meta: ^1.9.0
     ",
     )?;
     pubspec.flush()?;
@@ -56,9 +102,23 @@ pub fn run_test(fixture: &str, udl_path: &str, config_path: Option<&str>) -> Res
         config_path.as_deref(),
         Some(&out_dir),
         &test_helper.cdylib_path()?,
-        false, // library_mode
+        library_mode,
     )?;
-    for file in glob::glob(&format!("**/*.dart"))?.filter_map(Result::ok) {
+    // Also expose the bindings as the `uniffi_test` package's library, so that fixtures can
+    // import each other through `package:uniffi_test/...` via `external_packages`
+    let lib_outdir = out_dir.join("lib");
+    create_dir_all(&lib_outdir)?;
+    for file in glob::glob(out_dir.join("*.dart").as_str())?.filter_map(Result::ok) {
+        copy(
+            &file,
+            lib_outdir.join(
+                file.file_name()
+                    .and_then(|f| f.to_str())
+                    .expect("bad filename"),
+            ),
+        )?;
+    }
+    for file in glob::glob("**/*.dart")?.filter_map(Result::ok) {
         copy(
             &file,
             out_dir.join(file.as_os_str().to_str().expect("bad filename")),